@@ -0,0 +1,195 @@
+use ethers::types::U256;
+
+/// The number of mathematical constants in each table built by
+/// [`constant_table`].
+pub const CONSTANT_COUNT: usize = 6;
+
+/// The index of `e` in the constant tables.
+pub(crate) const E: usize = 0;
+
+/// The index of `ln(2)` in the constant tables.
+pub(crate) const LN_2: usize = 1;
+
+/// The index of `ln(10)` in the constant tables.
+pub(crate) const LN_10: usize = 2;
+
+/// The index of `π` in the constant tables.
+pub(crate) const PI: usize = 3;
+
+/// The index of `√2` in the constant tables.
+pub(crate) const SQRT_2: usize = 4;
+
+/// The index of the number of seconds in a 365 day year in the constant
+/// tables.
+pub(crate) const SECONDS_PER_YEAR: usize = 5;
+
+/// The decimal digits of each constant along with the number of digits before
+/// the decimal point. The digits extend well past the 77 decimals that fit in
+/// a `U256`, so the values are correctly rounded at every scale. Missing
+/// digits are zero.
+const CONSTANT_DIGITS: [(&[u8], usize); CONSTANT_COUNT] = [
+    (
+        b"27182818284590452353602874713526624977572470936999595749669676277240766303535475945713821785251664274",
+        1,
+    ),
+    (
+        b"6931471805599453094172321214581765680755001343602552541206800094933936219696947156058633269964186875",
+        0,
+    ),
+    (
+        b"23025850929940456840179914546843642076011014886287729760333279009675726096773524802359972050895982983",
+        1,
+    ),
+    (
+        b"31415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679",
+        1,
+    ),
+    (
+        b"14142135623730950488016887242096980785696718753769480731766797379907324784621070388503875343276415727",
+        1,
+    ),
+    (b"31536000", 8),
+];
+
+/// The number of digits before the decimal point of the constant at `index`.
+/// A constant can be represented with up to `MAX_DECIMALS` minus this many
+/// decimals, since its value is then less than `10^MAX_DECIMALS`.
+pub(crate) const fn integer_digits(index: usize) -> usize {
+    CONSTANT_DIGITS[index].1
+}
+
+/// Computes each constant rounded to the nearest value with `0..N` decimals
+/// as `U256`s at compile time. The values for the constant at index `i` start
+/// at index `i * N`. Values that would have more than `N - 1` digits in total
+/// are zero.
+///
+/// # Panics
+///
+/// If `M` isn't `CONSTANT_COUNT * N` or `N` is greater than `78`.
+pub const fn constant_table<const N: usize, const M: usize>() -> [U256; M] {
+    if M != CONSTANT_COUNT * N {
+        panic!("Constant table has the wrong size.");
+    }
+
+    let mut table = [U256([0, 0, 0, 0]); M];
+    let mut i = 0;
+    while i < CONSTANT_COUNT {
+        let (digits, integer_digits) = CONSTANT_DIGITS[i];
+
+        // Start with the integer part, then add one digit for each decimal,
+        // rounding up if the following digit is at least 5.
+        let mut value = U256([0, 0, 0, 0]);
+        let mut j = 0;
+        while j < integer_digits {
+            value = mul_add(value, digit(digits, j));
+            j += 1;
+        }
+        let mut decimals = 0;
+        loop {
+            let next = digit(digits, integer_digits + decimals);
+            table[i * N + decimals] = if next >= 5 { add_one(value) } else { value };
+            decimals += 1;
+            if decimals + integer_digits >= N {
+                break;
+            }
+            value = mul_add(value, next);
+        }
+        i += 1;
+    }
+    table
+}
+
+/// Returns the digit at `index` of `digits`, or zero if it's out of range.
+const fn digit(digits: &[u8], index: usize) -> u64 {
+    if index < digits.len() {
+        (digits[index] - b'0') as u64
+    } else {
+        0
+    }
+}
+
+/// Computes `value * 10 + digit` one 64-bit limb at a time.
+const fn mul_add(value: U256, digit: u64) -> U256 {
+    let U256(limbs) = value;
+    let mut result = [0_u64; 4];
+    let mut carry = digit as u128;
+    let mut i = 0;
+    while i < 4 {
+        let product = limbs[i] as u128 * 10 + carry;
+        result[i] = product as u64;
+        carry = product >> 64;
+        i += 1;
+    }
+    if carry != 0 {
+        panic!("Constant overflows U256.");
+    }
+    U256(result)
+}
+
+/// Computes `value + 1` one 64-bit limb at a time.
+const fn add_one(value: U256) -> U256 {
+    let U256(limbs) = value;
+    let mut result = [0_u64; 4];
+    let mut carry = 1_u128;
+    let mut i = 0;
+    while i < 4 {
+        let sum = limbs[i] as u128 + carry;
+        result[i] = sum as u64;
+        carry = sum >> 64;
+        i += 1;
+    }
+    U256(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{I256, U256};
+
+    use crate::{fixed, int256, uint256, FixedPoint};
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(FixedPoint::<I256>::E, fixed!(2.718281828459045235e18));
+        assert_eq!(FixedPoint::<I256>::LN_2, fixed!(0.693147180559945309e18));
+        assert_eq!(FixedPoint::<I256>::LN_10, fixed!(2.302585092994045684e18));
+        assert_eq!(FixedPoint::<I256>::PI, fixed!(3.141592653589793238e18));
+        assert_eq!(FixedPoint::<I256>::SQRT_2, fixed!(1.414213562373095049e18));
+        assert_eq!(FixedPoint::<I256>::SECONDS_PER_YEAR, fixed!(31_536_000e18));
+
+        // The constants are rounded to nearest at every scale.
+        assert_eq!(FixedPoint::<U256, 6>::E.raw(), uint256!(2_718_282));
+        assert_eq!(FixedPoint::<u128, 6>::PI.raw(), 3_141_593);
+        assert_eq!(FixedPoint::<u128, 0>::E.raw(), 3);
+        assert_eq!(FixedPoint::<i128, 0>::LN_2.raw(), 1);
+        assert_eq!(
+            FixedPoint::<U256, 0>::SECONDS_PER_YEAR.raw(),
+            uint256!(31_536_000)
+        );
+        assert_eq!(
+            FixedPoint::<i128, 36>::LN_2.raw(),
+            693_147_180_559_945_309_417_232_121_458_176_568
+        );
+        assert_eq!(
+            FixedPoint::<i128, 37>::LN_10.raw(),
+            23_025_850_929_940_456_840_179_914_546_843_642_076
+        );
+        assert_eq!(
+            FixedPoint::<i128, 30>::SECONDS_PER_YEAR.raw(),
+            31_536_000_000_000_000_000_000_000_000_000_000_000
+        );
+
+        // The largest scales that fit each constant.
+        assert_eq!(
+            FixedPoint::<I256, 75>::E.raw(),
+            int256!(2718281828459045235360287471352662497757247093699959574966967627724076630354)
+        );
+        assert_eq!(
+            FixedPoint::<I256, 75>::SQRT_2.raw(),
+            int256!(1414213562373095048801688724209698078569671875376948073176679737990732478462)
+        );
+        assert_eq!(
+            FixedPoint::<U256, 77>::LN_2.raw(),
+            uint256!(69314718055994530941723212145817656807550013436025525412068000949339362196969)
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use std::{any::type_name, fmt};
+
+/// An error returned by a fallible `FixedPoint` operation.
+///
+/// The `ExpInvalidExponent`, `LnInvalidInput` and `UnsafeCastToInt256`
+/// variants mirror the custom errors of the same name in the Solidity
+/// implementation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FixedPointError {
+    /// The result of the operation can't be represented by the underlying
+    /// type.
+    Overflow,
+    /// The operation attempted to divide by zero.
+    DivisionByZero,
+    /// A value is outside of the range of the type it's being converted to.
+    /// The `value` is the unscaled integer, e.g., `"-1"` for a raw value of
+    /// -1 rather than the scaled `"-0.000000000000000001"`.
+    ConversionOutOfRange { value: String, to: &'static str },
+    /// A number string contains a character other than a digit, `.`, `e`, `_`
+    /// or a leading `-`.
+    InvalidCharacter { input: String, character: char },
+    /// A number string's exponent is too small to make it an integer, e.g.,
+    /// `1.5` or `1.23e1`.
+    ExponentTooSmall { input: String },
+    /// The input to `exp` is too large for the result to fit in an `I256`.
+    ExpInvalidExponent,
+    /// The input to `ln` is negative or zero.
+    LnInvalidInput,
+    /// The value is too large to be converted to an `I256`.
+    UnsafeCastToInt256,
+    /// The degree of a root is zero or too large, or the input to an even root
+    /// is negative.
+    RootInvalidInput,
+    /// `y * ln(x)` is too large for `exp` when computing `x^y`.
+    PowOverflow { base: String, exponent: String },
+    /// The base of `x^y` is negative and the exponent isn't an integer, so
+    /// the result isn't a real number.
+    PowInvalidInput { base: String, exponent: String },
+    /// The input to the inverse normal CDF isn't strictly between zero and
+    /// one.
+    NormInvCdfInvalidInput,
+    /// The input to a geometric mean is empty, contains a negative value or
+    /// weight, or has weights that sum to zero.
+    MeanInvalidInput,
+    /// The input to a function in `stats` is empty, contains a negative
+    /// weight, or has weights that sum to zero, or the percentile or smoothing
+    /// factor isn't in `[0, 1]`.
+    StatsInvalidInput,
+}
+
+impl FixedPointError {
+    /// Creates a `ConversionOutOfRange` error for a value that doesn't fit in
+    /// `T`. The `value` should be the unscaled integer, which is formatted with
+    /// `Debug`.
+    pub(crate) fn conversion<T>(value: impl fmt::Debug) -> Self {
+        let name = type_name::<T>();
+        FixedPointError::ConversionOutOfRange {
+            value: format!("{value:?}"),
+            to: name.rsplit("::").next().unwrap_or(name),
+        }
+    }
+}
+
+impl fmt::Display for FixedPointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FixedPointError::Overflow => write!(f, "FixedPoint operation overflowed"),
+            FixedPointError::DivisionByZero => write!(f, "Cannot divide by zero"),
+            FixedPointError::ConversionOutOfRange { value, to } => {
+                write!(f, "Value {value} is out of range for {to}")
+            }
+            FixedPointError::InvalidCharacter { input, character } => {
+                write!(f, "Unexpected character {character:?} in number: {input}")
+            }
+            FixedPointError::ExponentTooSmall { input } => {
+                write!(
+                    f,
+                    "Exponent is too small to make the number an integer: {input}"
+                )
+            }
+            FixedPointError::ExpInvalidExponent => write!(f, "Invalid exponent for exp"),
+            FixedPointError::LnInvalidInput => {
+                write!(f, "Cannot calculate ln of a negative number or zero")
+            }
+            FixedPointError::UnsafeCastToInt256 => {
+                write!(f, "Value is too large to convert to I256")
+            }
+            FixedPointError::RootInvalidInput => {
+                write!(
+                    f,
+                    "Cannot calculate a root of degree zero or above 1,000, or an even root of a negative number"
+                )
+            }
+            FixedPointError::PowOverflow { base, exponent } => {
+                write!(
+                    f,
+                    "Cannot calculate {base}^{exponent} because y * ln(x) is too large for exp"
+                )
+            }
+            FixedPointError::PowInvalidInput { base, exponent } => {
+                write!(
+                    f,
+                    "Cannot raise the negative number {base} to the non-integer power {exponent}"
+                )
+            }
+            FixedPointError::NormInvCdfInvalidInput => {
+                write!(
+                    f,
+                    "Cannot calculate the inverse normal CDF of a number outside of (0, 1)"
+                )
+            }
+            FixedPointError::MeanInvalidInput => {
+                write!(
+                    f,
+                    "Cannot calculate a geometric mean of no values, negative values or weights, or weights that sum to zero"
+                )
+            }
+            FixedPointError::StatsInvalidInput => {
+                write!(
+                    f,
+                    "Cannot calculate a statistic of no values, negative weights, or weights that sum to zero, or with a percentile or smoothing factor outside of [0, 1]"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FixedPointError {}
@@ -1,57 +1,90 @@
 use std::fmt::{self, Debug};
 
-use ethers::types::{I256, U256};
-use eyre::{bail, eyre, Result};
+use ethers::types::{I256, U256, U512};
 
-use crate::{sign::FixedPointSign, utils::u256_from_str, value::FixedPointValue};
+use crate::{
+    constants, error::FixedPointError, rounding::RoundingMode, sign::FixedPointSign,
+    utils::u256_from_str, value::FixedPointValue,
+};
+
+/// The number of decimal places used by `FixedPoint` when none are specified.
+pub const DEFAULT_DECIMALS: u8 = 18;
 
 /// A generic fixed point type built on ethers-rs.
+///
+/// The number of decimal places, `D`, is part of the type, so values with
+/// different scales, e.g., `FixedPoint<U256, 6>` and `FixedPoint<U256, 18>`,
+/// can't be mixed without an explicit conversion via
+/// [`FixedPoint::change_decimals`]. `D` must be at most `T::MAX_DECIMALS`.
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
-pub struct FixedPoint<T: FixedPointValue> {
+pub struct FixedPoint<T: FixedPointValue, const D: u8 = DEFAULT_DECIMALS> {
     raw: T,
-    decimals: u8,
 }
 
-impl<T: FixedPointValue> FixedPoint<T> {
-    pub const MIN: Self = Self {
-        raw: T::MIN,
-        decimals: T::MAX_DECIMALS,
-    };
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    pub const MIN: Self = Self { raw: T::MIN };
+
+    pub const MAX: Self = Self { raw: T::MAX };
 
-    pub const MAX: Self = Self {
-        raw: T::MAX,
-        decimals: T::MAX_DECIMALS,
+    /// One at this scale, i.e., `10^D`.
+    ///
+    /// Using this constant with a `D` greater than `T::MAX_DECIMALS` is a
+    /// compile-time error.
+    pub const ONE: Self = Self {
+        raw: T::POWERS_OF_TEN[D as usize],
     };
 
-    // Constructors //
+    /// Euler's number, `e`, correctly rounded at this scale.
+    ///
+    /// Like the other constants, this is rounded to the nearest value for any
+    /// `D`. Using a constant with more decimals than `T::MAX_DECIMALS` minus
+    /// the number of digits before its decimal point is a compile-time error.
+    pub const E: Self = Self::constant(constants::E);
 
-    pub fn new<V: Into<T>>(value: V) -> Self {
+    /// The natural logarithm of 2, `ln(2)`, correctly rounded at this scale.
+    pub const LN_2: Self = Self::constant(constants::LN_2);
+
+    /// The natural logarithm of 10, `ln(10)`, correctly rounded at this
+    /// scale.
+    pub const LN_10: Self = Self::constant(constants::LN_10);
+
+    /// Archimedes' constant, `π`, correctly rounded at this scale.
+    pub const PI: Self = Self::constant(constants::PI);
+
+    /// The square root of 2, `√2`, correctly rounded at this scale.
+    pub const SQRT_2: Self = Self::constant(constants::SQRT_2);
+
+    /// The number of seconds in a 365 day year, `31536000`.
+    pub const SECONDS_PER_YEAR: Self = Self::constant(constants::SECONDS_PER_YEAR);
+
+    /// Looks up the constant at `index` in `T::CONSTANTS` at this scale.
+    const fn constant(index: usize) -> Self {
+        if D as usize + constants::integer_digits(index) > T::MAX_DECIMALS as usize {
+            panic!("Constant can't be represented with this many decimals.");
+        }
         Self {
-            raw: value.into(),
-            // TODO: Add support for variable decimals.
-            decimals: T::MAX_DECIMALS,
+            raw: T::CONSTANTS[index * (T::MAX_DECIMALS as usize + 1) + D as usize],
         }
     }
 
-    pub fn try_from<V: TryInto<T> + Debug>(value: V) -> Result<Self> {
+    // Constructors //
+
+    pub fn new<V: Into<T>>(value: V) -> Self {
+        Self { raw: value.into() }
+    }
+
+    pub fn try_from<V: TryInto<T> + Debug>(value: V) -> Result<Self, FixedPointError> {
         // Convert the value to a Debug string before moving it incase the
         // conversion fails.
         let value_debug = format!("{:?}", value);
-        let value = value.try_into().map_err(|_| {
-            eyre!(
-                r#"Failed to convert value to underlying FixedPointValue type:
-    value: {value_debug}
-    Underlying range: {min:?} to {max:?}
-"#,
-                min = Self::MIN,
-                max = Self::MAX,
-            )
-        })?;
+        let value = value
+            .try_into()
+            .map_err(|_| FixedPointError::conversion::<T>(format_args!("{value_debug}")))?;
 
         Ok(Self::new(value))
     }
 
-    pub fn from_sign_and_abs(sign: FixedPointSign, abs: U256) -> Result<Self> {
+    pub fn from_sign_and_abs(sign: FixedPointSign, abs: U256) -> Result<Self, FixedPointError> {
         Ok(match sign {
             FixedPointSign::Positive => Self::new(T::from_u256(abs)?),
             FixedPointSign::Negative => {
@@ -62,6 +95,10 @@ impl<T: FixedPointValue> FixedPoint<T> {
                     // sign after the fact will overflow, so we just return the
                     // MIN value directly in this case.
                     Self::MIN
+                } else if !T::is_signed() {
+                    // Unsigned types can't represent negative values, so this
+                    // is a conversion error rather than a failed sign flip.
+                    return Err(FixedPointError::conversion::<T>(format_args!("-{abs}")));
                 } else {
                     let raw = T::from_u256(abs)?.flip_sign();
                     Self::new(raw)
@@ -70,7 +107,7 @@ impl<T: FixedPointValue> FixedPoint<T> {
         })
     }
 
-    pub fn from_dec_str(s: &str) -> Result<Self> {
+    pub fn from_dec_str(s: &str) -> Result<Self, FixedPointError> {
         if s.starts_with('-') {
             Self::from_sign_and_abs(FixedPointSign::Negative, u256_from_str(&s[1..])?)
         } else {
@@ -90,12 +127,8 @@ impl<T: FixedPointValue> FixedPoint<T> {
     }
 
     /// One with the same scale as this fixed point number, i.e., `1.0`.
-    ///
-    /// # Panics
-    ///
-    /// Panics if `10` to the power of `self.decimals()` overflows `T`.
     pub fn one(&self) -> Self {
-        Self::try_from(10_u128.pow(self.decimals().into())).unwrap()
+        Self::ONE
     }
 
     // Getters //
@@ -107,7 +140,7 @@ impl<T: FixedPointValue> FixedPoint<T> {
     }
 
     pub fn decimals(&self) -> u8 {
-        self.decimals
+        D
     }
 
     pub fn sign(&self) -> FixedPointSign {
@@ -147,44 +180,81 @@ impl<T: FixedPointValue> FixedPoint<T> {
     /// let fp_u128: FixedPoint<u128> = fp_i128.change_type()?;
     /// assert_eq!(fp_u128, fixed_u128!(1));
     /// ```
-    pub fn change_type<U: FixedPointValue + TryFrom<T>>(self) -> Result<FixedPoint<U>> {
-        self.raw().try_to_fixed()
+    pub fn change_type<U: FixedPointValue + TryFrom<T>>(
+        self,
+    ) -> Result<FixedPoint<U, D>, FixedPointError> {
+        Ok(FixedPoint::new(self.raw().try_to_fixed::<U>()?.raw()))
+    }
+
+    // Conversion to other scales //
+
+    /// Creates a `FixedPoint` instance with the same value as this instance but
+    /// with a different number of decimals, rounding in the given direction
+    /// if the value can't be represented exactly at the new scale.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let dai = fixed_u256!(1.2345675e18);
+    ///
+    /// let usdc = dai.change_decimals::<6>(RoundingMode::Down)?;
+    /// assert_eq!(usdc.raw(), uint256!(1.234567e6));
+    ///
+    /// let usdc: FixedPoint<U256, 6> = dai.change_decimals(RoundingMode::Up)?;
+    /// assert_eq!(usdc.raw(), uint256!(1.234568e6));
+    /// ```
+    pub fn change_decimals<const E: u8>(
+        self,
+        rounding: RoundingMode,
+    ) -> Result<FixedPoint<T, E>, FixedPointError> {
+        let divisor = U512::from(Self::ONE.raw().unsigned_abs());
+        let (abs, rem) = self
+            .raw()
+            .unsigned_abs()
+            .full_mul(FixedPoint::<T, E>::ONE.raw().unsigned_abs())
+            .div_mod(divisor);
+        let mut abs = U256::try_from(abs).map_err(|_| FixedPointError::Overflow)?;
+        if rounding.rounds_away_from_zero(self.sign(), abs.bit(0), rem, divisor) {
+            abs = abs.checked_add(1.into()).ok_or(FixedPointError::Overflow)?;
+        }
+        FixedPoint::from_sign_and_abs(self.sign(), abs).map_err(|_| FixedPointError::Overflow)
     }
 
     // Conversion to unsigned & signed ethers types //
 
-    pub fn to_u256(self) -> Result<U256> {
+    pub fn to_u256(self) -> Result<U256, FixedPointError> {
         if self.is_negative() {
-            bail!("Cannot convert negative FixedPoint {self} to U256.");
+            return Err(FixedPointError::conversion::<U256>(self.raw()));
         }
         self.raw().to_u256()
     }
 
-    pub fn to_i256(self) -> Result<I256> {
+    pub fn to_i256(self) -> Result<I256, FixedPointError> {
         let abs = self.unsigned_abs().raw();
         let abs_max = FixedPoint::<I256>::saturate_sign(self.sign())
             .raw()
             .unsigned_abs();
         if abs > abs_max {
-            bail!("FixedPoint {self} is too large to convert to I256.");
+            return Err(FixedPointError::UnsafeCastToInt256);
         }
         I256::checked_from_sign_and_abs(self.sign().into(), abs)
-            .ok_or(eyre!("Failed to convert FixedPoint {self} to I256."))
+            .ok_or(FixedPointError::UnsafeCastToInt256)
     }
 
     // Conversion to unsigned and signed std types //
 
-    pub fn to_u128(self) -> Result<u128> {
+    pub fn to_u128(self) -> Result<u128, FixedPointError> {
         if self.is_negative() {
-            bail!("Cannot convert negative FixedPoint {self} to u128.");
+            return Err(FixedPointError::conversion::<u128>(self.raw()));
         }
         self.raw().to_u128()
     }
 
-    pub fn to_i128(self) -> Result<i128> {
-        let i256 = self.to_i256()?;
-        i128::try_from(i256)
-            .or_else(|_| bail!("FixedPoint {self} is too large to convert to i128."))
+    pub fn to_i128(self) -> Result<i128, FixedPointError> {
+        self.to_i256()
+            .ok()
+            .and_then(|i256| i128::try_from(i256).ok())
+            .ok_or(FixedPointError::conversion::<i128>(self.raw()))
     }
 
     // Formatting //
@@ -228,19 +298,19 @@ impl<T: FixedPointValue> FixedPoint<T> {
 
 // Trait implementations //
 
-impl<T: FixedPointValue> Default for FixedPoint<T> {
+impl<T: FixedPointValue, const D: u8> Default for FixedPoint<T, D> {
     fn default() -> Self {
         Self::new(T::default())
     }
 }
 
-impl<T: FixedPointValue> fmt::Debug for FixedPoint<T> {
+impl<T: FixedPointValue, const D: u8> fmt::Debug for FixedPoint<T, D> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "FixedPoint({})", self.to_scaled_string())
     }
 }
 
-impl<T: FixedPointValue> fmt::Display for FixedPoint<T> {
+impl<T: FixedPointValue, const D: u8> fmt::Display for FixedPoint<T, D> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.to_scaled_string())
     }
@@ -249,7 +319,7 @@ impl<T: FixedPointValue> fmt::Display for FixedPoint<T> {
 // Conversions //
 
 // Basic raw to FixedPoint conversion.
-impl<T: FixedPointValue> From<T> for FixedPoint<T> {
+impl<T: FixedPointValue, const D: u8> From<T> for FixedPoint<T, D> {
     fn from(value: T) -> Self {
         Self::new(value)
     }
@@ -313,7 +383,9 @@ pub trait ToFixed: Sized + Debug {
     /// let a = 100.try_to_fixed::<U256>();  // -> Ok(FixedPoint<U256>)
     /// let b = -100.try_to_fixed::<U256>(); // -> Err(...)
     /// ```
-    fn try_to_fixed<T: FixedPointValue + TryFrom<Self>>(self) -> Result<FixedPoint<T>> {
+    fn try_to_fixed<T: FixedPointValue + TryFrom<Self>>(
+        self,
+    ) -> Result<FixedPoint<T>, FixedPointError> {
         FixedPoint::<T>::try_from(self)
     }
 }
@@ -327,22 +399,17 @@ impl<T: Sized + Debug> ToFixed for T {}
 macro_rules! conversion_impls {
     ($($t:ty),*) => {
         $(
-            impl<T: FixedPointValue + From<$t>> From<$t> for FixedPoint<T> {
+            impl<T: FixedPointValue + From<$t>, const D: u8> From<$t> for FixedPoint<T, D> {
                 fn from(u: $t) -> Self {
                     Self::new(u)
                 }
             }
 
-            impl<T: FixedPointValue + TryInto<$t>> TryFrom<FixedPoint<T>> for $t {
-                type Error = eyre::ErrReport;
+            impl<T: FixedPointValue + TryInto<$t>, const D: u8> TryFrom<FixedPoint<T, D>> for $t {
+                type Error = FixedPointError;
 
-                fn try_from(f: FixedPoint<T>) -> eyre::Result<Self> {
-                    f.raw().try_into().map_err(|_| {
-                        eyre!(
-                            "Failed to convert underlying FixedPointValue to {type}: {f:?}",
-                            type = stringify!($t)
-                        )
-                    })
+                fn try_from(f: FixedPoint<T, D>) -> Result<Self, FixedPointError> {
+                    f.raw().try_into().map_err(|_| FixedPointError::conversion::<$t>(f.raw()))
                 }
             }
         )*
@@ -356,13 +423,153 @@ conversion_impls!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize, [u8; 32],
 
 #[cfg(test)]
 mod tests {
-    use crate::fixed_i128;
+    use ethers::types::U256;
+
+    use super::*;
+    use crate::{fixed, fixed_i128, fixed_u256, uint256};
+
+    #[test]
+    fn test_decimals() {
+        let usdc = FixedPoint::<U256, 6>::new(uint256!(1.5e6));
+        assert_eq!(usdc.decimals(), 6);
+        assert_eq!(usdc.one().raw(), uint256!(1e6));
+        assert_eq!(usdc.to_string(), "1.500000");
+
+        let ray = FixedPoint::<U256, 27>::ONE;
+        assert_eq!(ray.raw(), uint256!(1e27));
+        assert_eq!(ray.to_string(), "1.000000000000000000000000000");
+
+        // The scale defaults to 18 decimals.
+        assert_eq!(fixed_u256!(1e18), FixedPoint::<U256, 18>::ONE);
+        assert_eq!(FixedPoint::<i128>::ONE.decimals(), DEFAULT_DECIMALS);
+
+        // The largest scale supported by each underlying type.
+        assert_eq!(FixedPoint::<u128, 38>::ONE.raw(), 10_u128.pow(38));
+        assert_eq!(FixedPoint::<i128, 38>::ONE.raw(), 10_i128.pow(38));
+        assert_eq!(FixedPoint::<I256, 76>::ONE.raw(), I256::exp10(76));
+        assert_eq!(FixedPoint::<U256, 77>::ONE.raw(), U256::exp10(77));
+        assert_eq!(FixedPoint::<U256, 0>::ONE.raw(), U256::one());
+    }
+
+    #[test]
+    fn test_change_decimals() {
+        let dai = fixed_u256!(1.2345675e18);
+        let usdc = dai.change_decimals::<6>(RoundingMode::Down).unwrap();
+        assert_eq!(usdc.raw(), uint256!(1.234567e6));
+        let usdc: FixedPoint<U256, 6> = dai.change_decimals(RoundingMode::Up).unwrap();
+        assert_eq!(usdc.raw(), uint256!(1.234568e6));
+        assert_eq!(
+            usdc.change_decimals::<18>(RoundingMode::Down).unwrap(),
+            fixed!(1.234568e18)
+        );
+
+        // Rounding is applied to the magnitude of negative values.
+        let x = fixed_i128!(-1.1234565e18);
+        assert_eq!(
+            x.change_decimals::<6>(RoundingMode::Down).unwrap().raw(),
+            -1_123_456
+        );
+        assert_eq!(
+            x.change_decimals::<6>(RoundingMode::Up).unwrap().raw(),
+            -1_123_457
+        );
+        assert_eq!(
+            x.change_decimals::<6>(RoundingMode::Floor).unwrap().raw(),
+            -1_123_457
+        );
+        assert_eq!(
+            x.change_decimals::<6>(RoundingMode::Ceil).unwrap().raw(),
+            -1_123_456
+        );
+        assert_eq!(
+            x.change_decimals::<6>(RoundingMode::HalfUp).unwrap().raw(),
+            -1_123_457
+        );
+        assert_eq!(
+            x.change_decimals::<6>(RoundingMode::HalfEven)
+                .unwrap()
+                .raw(),
+            -1_123_456
+        );
+
+        // Increasing the number of decimals can overflow.
+        assert!(FixedPoint::<u128>::MAX
+            .change_decimals::<38>(RoundingMode::Down)
+            .is_err());
+    }
 
     #[test]
     fn test_change_type_failure() {
         let fixed = fixed_i128!(-1);
         let fixed_u128 = fixed.change_type::<u128>();
-        assert!(fixed_u128.is_err());
+        assert_eq!(
+            fixed_u128,
+            Err(FixedPointError::ConversionOutOfRange {
+                value: "-1".to_string(),
+                to: "u128"
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_sign_and_abs() {
+        assert_eq!(
+            FixedPoint::<i128>::from_sign_and_abs(FixedPointSign::Negative, uint256!(1e18)),
+            Ok(fixed!(-1e18))
+        );
+        assert_eq!(
+            FixedPoint::<i128>::from_sign_and_abs(
+                FixedPointSign::Negative,
+                U256::from(i128::MIN.unsigned_abs())
+            ),
+            Ok(FixedPoint::MIN)
+        );
+        assert_eq!(
+            FixedPoint::<U256>::from_sign_and_abs(FixedPointSign::Negative, uint256!(0)),
+            Ok(fixed!(0))
+        );
+
+        // Negative values of unsigned types are conversion errors.
+        assert_eq!(
+            FixedPoint::<U256>::from_sign_and_abs(FixedPointSign::Negative, uint256!(1)),
+            Err(FixedPointError::ConversionOutOfRange {
+                value: "-1".to_string(),
+                to: "U256"
+            })
+        );
+        assert!(matches!(
+            FixedPoint::<u128>::from_sign_and_abs(FixedPointSign::Negative, uint256!(1e18)),
+            Err(FixedPointError::ConversionOutOfRange { to: "u128", .. })
+        ));
+    }
+
+    #[test]
+    fn test_conversion_failure() {
+        assert_eq!(
+            fixed_i128!(-1e18).to_u256(),
+            Err(FixedPointError::ConversionOutOfRange {
+                value: "-1000000000000000000".to_string(),
+                to: "U256"
+            })
+        );
+        assert_eq!(
+            FixedPoint::<U256>::MAX.to_i256(),
+            Err(FixedPointError::UnsafeCastToInt256)
+        );
+        assert_eq!(
+            fixed_u256!(1e40).to_i128(),
+            Err(FixedPointError::ConversionOutOfRange {
+                value: "10000000000000000000000000000000000000000".to_string(),
+                to: "i128"
+            })
+        );
+        assert_eq!(
+            u8::try_from(fixed_u256!(256)),
+            Err(FixedPointError::ConversionOutOfRange {
+                value: "256".to_string(),
+                to: "u8"
+            })
+        );
     }
 
     #[test]
@@ -0,0 +1,338 @@
+use ethers::types::{I256, U256, U512};
+use num_bigint::BigUint;
+
+use crate::{
+    roots::{rounded_root, to_biguint, MAX_ROOT_DEGREE},
+    FixedPoint, FixedPointError, FixedPointSign, FixedPointValue, RoundingMode,
+};
+
+/// The amount, with 36 decimals, by which the mean logarithm is moved in the
+/// direction of the rounding mode. It's well above the combined error of
+/// `ln_36` and `exp_36`, which are each off by less than `1e-33`.
+const MAX_LN_36_ERROR: i128 = 10_000;
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Computes the geometric mean of `values`, `(x_1 * ... * x_n)^(1 / n)`,
+    /// rounded to the nearest value. Returns an error if `values` is empty or
+    /// contains a negative value.
+    ///
+    /// The mean is computed as `exp(Σ ln(x) / n)` with 36 decimals, so the
+    /// product can't overflow and the result has a relative error below
+    /// `1e-32` before it's rounded. That's less than one unit in the last
+    /// place for results below `5e13` with 18 decimals, but larger results
+    /// can be off by more. The result is clamped to the range of the values,
+    /// so the mean of equal values is exact.
+    pub fn geometric_mean(values: &[Self]) -> Result<Self, FixedPointError> {
+        Self::geometric_mean_36(
+            values.iter().map(|&value| (value, Self::ONE)),
+            RoundingMode::HalfUp,
+        )
+    }
+
+    /// Computes the weighted geometric mean of `(value, weight)` pairs,
+    /// `(x_1^w_1 * ... * x_n^w_n)^(1 / Σ w)`, rounding according to `mode`.
+    /// Returns an error if `values` is empty, contains a negative value or
+    /// weight, or its weights sum to zero. When the weights sum to one, this
+    /// is the invariant of a weighted pool.
+    ///
+    /// The mean is computed as `exp(Σ w * ln(x) / Σ w)` with 36 decimals, so
+    /// it has a relative error below `1e-32` before it's rounded. The
+    /// directional modes move the logarithm by `1e-32` in their direction
+    /// before rounding, so the result is always on the correct side of the
+    /// true value and within a relative error of `2e-32` of it. That's one
+    /// unit in the last place for results below `5e13` with 18 decimals, but
+    /// larger results can be off by more. The result is clamped to the range
+    /// of the values with nonzero weight. Use `weighted_geometric_mean_powi`
+    /// for exact results with integer weights.
+    pub fn weighted_geometric_mean(
+        values: &[(Self, Self)],
+        mode: RoundingMode,
+    ) -> Result<Self, FixedPointError> {
+        Self::geometric_mean_36(values.iter().copied(), mode)
+    }
+
+    /// Computes the weighted geometric mean of `(value, weight)` pairs with
+    /// integer weights, rounding according to `mode`. Returns an error if
+    /// `values` contains a negative value or the weights sum to zero or more
+    /// than 1,000.
+    ///
+    /// Rather than going through `ln` and `exp`, the product of the powers is
+    /// computed exactly and its root is taken with integer Newton iterations,
+    /// so the result is correctly rounded. The product has about as many
+    /// digits as the values times the sum of the weights, which is bounded to
+    /// keep the cost reasonable.
+    pub fn weighted_geometric_mean_powi(
+        values: &[(Self, u32)],
+        mode: RoundingMode,
+    ) -> Result<Self, FixedPointError> {
+        let mut total_weight = 0_u32;
+        for &(value, weight) in values {
+            if value.is_negative() {
+                return Err(FixedPointError::MeanInvalidInput);
+            }
+            total_weight = total_weight.saturating_add(weight);
+        }
+        if total_weight == 0 || total_weight > MAX_ROOT_DEGREE {
+            return Err(FixedPointError::MeanInvalidInput);
+        }
+        // The mean of `raw / ONE` at the scale of `ONE` is the root of
+        // `raw_1^w_1 * ... * raw_n^w_n`, since the powers of `ONE` cancel.
+        let radicand = values
+            .iter()
+            .map(|&(value, weight)| to_biguint(value.raw().unsigned_abs()).pow(weight))
+            .product::<BigUint>();
+        let root = rounded_root(&radicand, total_weight, FixedPointSign::Positive, mode);
+
+        // The mean is at most the largest value, so it always fits in `T`.
+        let abs = U256::from_little_endian(&root.to_bytes_le());
+        Ok(Self::from_sign_and_abs(FixedPointSign::Positive, abs).unwrap())
+    }
+
+    /// Computes the weighted geometric mean of `(value, weight)` pairs as
+    /// `exp(Σ w * ln(x) / Σ w)` with 36 decimals, rounding according to
+    /// `mode`.
+    fn geometric_mean_36(
+        values: impl Iterator<Item = (Self, Self)>,
+        mode: RoundingMode,
+    ) -> Result<Self, FixedPointError> {
+        // Accumulate the positive and negative weighted logarithms separately
+        // so that the sums can't overflow.
+        let mut total_weight = U512::zero();
+        let mut positive = U512::zero();
+        let mut negative = U512::zero();
+        let mut has_zero = false;
+        let mut min = Self::MAX;
+        let mut max = Self::zero();
+        for (value, weight) in values {
+            if value.is_negative() || weight.is_negative() {
+                return Err(FixedPointError::MeanInvalidInput);
+            }
+            if weight.is_zero() {
+                continue;
+            }
+            let weight = U512::from(weight.raw().unsigned_abs());
+            total_weight += weight;
+            if value.is_zero() {
+                has_zero = true;
+                continue;
+            }
+            min = min.min(value);
+            max = max.max(value);
+            let ln = value.ln_36()?;
+            if ln.is_negative() {
+                negative += weight * U512::from(ln.unsigned_abs());
+            } else {
+                positive += weight * U512::from(ln.unsigned_abs());
+            }
+        }
+        if total_weight.is_zero() {
+            return Err(FixedPointError::MeanInvalidInput);
+        }
+        if has_zero {
+            return Ok(Self::zero());
+        }
+
+        // The mean logarithm is between the logarithms of the values, so it
+        // fits in an `I256`.
+        let mean_ln = if positive >= negative {
+            I256::from_raw(U256::try_from((positive - negative) / total_weight).unwrap())
+        } else {
+            -I256::from_raw(U256::try_from((negative - positive) / total_weight).unwrap())
+        };
+        let mean_ln = match mode.for_magnitude(FixedPointSign::Positive) {
+            RoundingMode::Down | RoundingMode::Trunc => mean_ln - I256::from(MAX_LN_36_ERROR),
+            RoundingMode::Up | RoundingMode::Expand => mean_ln + I256::from(MAX_LN_36_ERROR),
+            _ => mean_ln,
+        };
+
+        // The mean is between the smallest and largest values, so it can only
+        // fall outside of them because of the approximation. `exp_36` only
+        // fails when the result overflows, which means it's above the largest
+        // value.
+        Ok(Self::exp_36(mean_ln, mode).map_or(max, |mean| mean.clamp(min, max)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::{fixed, fixed_i128, fixed_i256, fixed_u128, fixed_u256, uint256};
+
+    #[test]
+    fn test_geometric_mean() {
+        assert_eq!(
+            FixedPoint::geometric_mean(&[fixed_u256!(2e18), fixed!(8e18)]),
+            Ok(fixed!(4e18))
+        );
+        assert_eq!(
+            FixedPoint::geometric_mean(&[fixed_i256!(1e18), fixed!(2e18), fixed!(4e18)]),
+            Ok(fixed!(2e18))
+        );
+        assert_eq!(
+            FixedPoint::geometric_mean(&[fixed_u128!(1e18), fixed!(2e18)]),
+            Ok(fixed!(1.414213562373095049e18))
+        );
+        assert_eq!(
+            FixedPoint::geometric_mean(&[fixed_i128!(0.5e18), fixed!(0), fixed!(2e18)]),
+            Ok(fixed!(0))
+        );
+
+        // The product of the values would overflow.
+        assert_eq!(
+            FixedPoint::geometric_mean(&[fixed_u256!(1e25); 4]),
+            Ok(fixed!(1e25))
+        );
+
+        // The mean is clamped to the range of the values.
+        let max = FixedPoint::<U256>::MAX;
+        assert_eq!(FixedPoint::geometric_mean(&[max]), Ok(max));
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean(&[(max, fixed!(1e18)); 3], RoundingMode::Up),
+            Ok(max)
+        );
+        let x = fixed_u256!(1e40);
+        for mode in [RoundingMode::Down, RoundingMode::Up] {
+            assert_eq!(
+                FixedPoint::weighted_geometric_mean(&[(x, fixed!(1e18)), (x, fixed!(3e18))], mode),
+                Ok(x)
+            );
+        }
+
+        // Invalid inputs.
+        assert_eq!(
+            FixedPoint::<U256>::geometric_mean(&[]),
+            Err(FixedPointError::MeanInvalidInput)
+        );
+        assert_eq!(
+            FixedPoint::geometric_mean(&[fixed_i256!(1e18), fixed!(-1e18)]),
+            Err(FixedPointError::MeanInvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_weighted_geometric_mean() {
+        use RoundingMode::*;
+
+        // An 80/20 pool with 100 and 25 tokens.
+        let values = [
+            (fixed_u256!(100e18), fixed!(0.8e18)),
+            (fixed!(25e18), fixed!(0.2e18)),
+        ];
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean(&values, Down),
+            Ok(fixed!(75.785828325519904117e18))
+        );
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean(&values, Up),
+            Ok(fixed!(75.785828325519904118e18))
+        );
+
+        // The weights don't need to sum to one.
+        let values = [
+            (fixed_i128!(3e18), fixed!(2e18)),
+            (fixed!(24e18), fixed!(1e18)),
+        ];
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean(&values, HalfUp),
+            Ok(fixed!(6e18))
+        );
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean_powi(
+                &[(fixed_i128!(3e18), 2), (fixed!(24e18), 1)],
+                Down
+            ),
+            Ok(fixed!(6e18))
+        );
+
+        // The exact path rounds correctly.
+        let values = [(fixed_u256!(1e18), 1), (fixed!(2e18), 1)];
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean_powi(&values, Down),
+            Ok(fixed!(1.414213562373095048e18))
+        );
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean_powi(&values, Up),
+            Ok(fixed!(1.414213562373095049e18))
+        );
+
+        // The largest total weight.
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean_powi(
+                &[(fixed_u256!(2e18), 999), (fixed!(2e18), 1)],
+                Down
+            ),
+            Ok(fixed!(2e18))
+        );
+
+        // Values with zero weight are ignored.
+        let values = [(fixed_u256!(0), fixed!(0)), (fixed!(5e18), fixed!(1e18))];
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean(&values, HalfUp),
+            Ok(fixed!(5e18))
+        );
+
+        // Invalid inputs.
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean(&[(fixed_i256!(1e18), fixed!(-1e18))], Down),
+            Err(FixedPointError::MeanInvalidInput)
+        );
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean(&[(fixed_u256!(1e18), fixed!(0))], Down),
+            Err(FixedPointError::MeanInvalidInput)
+        );
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean_powi(&[(fixed_u256!(1e18), 0)], Down),
+            Err(FixedPointError::MeanInvalidInput)
+        );
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean_powi(
+                &[(fixed_u256!(1e18), u32::MAX), (fixed!(1e18), 1)],
+                Down
+            ),
+            Err(FixedPointError::MeanInvalidInput)
+        );
+        assert_eq!(
+            FixedPoint::weighted_geometric_mean_powi(
+                &[(fixed_u256!(2e18), 1_000), (fixed!(3e18), 1)],
+                Down
+            ),
+            Err(FixedPointError::MeanInvalidInput)
+        );
+    }
+
+    #[test]
+    fn fuzz_weighted_geometric_mean() {
+        use RoundingMode::*;
+
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            let values = (0..rng.gen_range(1..=5))
+                .map(|_| {
+                    (
+                        rng.gen_range(fixed_u256!(1)..=fixed!(1e58)),
+                        rng.gen_range(1..=4_u32),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let weighted = values
+                .iter()
+                .map(|&(value, weight)| {
+                    (value, FixedPoint::new(U256::from(weight) * uint256!(1e18)))
+                })
+                .collect::<Vec<_>>();
+
+            // The log domain results bound the correctly rounded results
+            // within a relative error of `2e-32` or one unit in the last
+            // place.
+            let down = FixedPoint::weighted_geometric_mean_powi(&values, Down).unwrap();
+            let up = FixedPoint::weighted_geometric_mean_powi(&values, Up).unwrap();
+            let tolerance = FixedPoint::new(up.raw() / uint256!(5e31)) + fixed!(1);
+            let actual = FixedPoint::weighted_geometric_mean(&weighted, Down).unwrap();
+            assert!(actual <= down && down - actual <= tolerance, "{values:?}");
+            let actual = FixedPoint::weighted_geometric_mean(&weighted, Up).unwrap();
+            assert!(actual >= up && actual - up <= tolerance, "{values:?}");
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use ethers::types::U256;
+
+use crate::{FixedPoint, FixedPointError, FixedPointValue, RoundingMode};
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Rounds self to the nearest integer toward negative infinity.
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows `T`.
+    pub fn floor(self) -> Self {
+        self.round(RoundingMode::Floor)
+    }
+
+    /// Rounds self to the nearest integer toward positive infinity.
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows `T`.
+    pub fn ceil(self) -> Self {
+        self.round(RoundingMode::Ceil)
+    }
+
+    /// Rounds self to an integer according to `mode`.
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows `T`.
+    pub fn round(self, mode: RoundingMode) -> Self {
+        self.checked_round(mode)
+            .unwrap_or_else(|err| panic!("{err}: round({self}, {mode:?})"))
+    }
+
+    /// Rounds self to an integer according to `mode`, returning an error if
+    /// the result overflows `T`.
+    pub fn checked_round(self, mode: RoundingMode) -> Result<Self, FixedPointError> {
+        let abs = self
+            .integer_abs(mode)
+            .checked_mul(Self::ONE.raw().unsigned_abs())
+            .ok_or(FixedPointError::Overflow)?;
+        Self::from_sign_and_abs(self.sign(), abs).map_err(|_| FixedPointError::Overflow)
+    }
+
+    /// Returns the integer part of self, rounding toward zero.
+    pub fn trunc(self) -> Self {
+        // Rounding toward zero can't overflow.
+        self.round(RoundingMode::Trunc)
+    }
+
+    /// Returns the fractional part of self, which has the same sign as self,
+    /// i.e., `self - self.trunc()`.
+    pub fn fract(self) -> Self {
+        self - self.trunc()
+    }
+
+    /// Rounds self to an integer according to `mode` and converts it to an
+    /// unscaled `U`, e.g., `fixed!(2.5e18).to_integer::<u128>(RoundingMode::HalfUp)` is
+    /// `3_u128`.
+    pub fn to_integer<U: FixedPointValue>(self, mode: RoundingMode) -> Result<U, FixedPointError> {
+        let abs = self.integer_abs(mode);
+        FixedPoint::<U, 0>::from_sign_and_abs(self.sign(), abs).map(|integer| integer.raw())
+    }
+
+    /// Computes the magnitude of self rounded to an integer according to
+    /// `mode`, without the scale.
+    fn integer_abs(self, mode: RoundingMode) -> U256 {
+        let one = Self::ONE.raw().unsigned_abs();
+        let (abs, rem) = self.raw().unsigned_abs().div_mod(one);
+        if mode.rounds_away_from_zero(self.sign(), abs.bit(0), rem.into(), one.into()) {
+            // There is only a remainder if `one` is at least 10, so this can't
+            // overflow.
+            return abs + 1;
+        }
+        abs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{I256, U256};
+
+    use super::*;
+    use crate::{fixed, fixed_i128, fixed_i256, fixed_u256, int256, uint256};
+
+    #[test]
+    fn test_round() {
+        use RoundingMode::*;
+
+        let x = fixed_i128!(2.5e18);
+        assert_eq!(x.floor(), fixed!(2e18));
+        assert_eq!(x.ceil(), fixed!(3e18));
+        assert_eq!(x.trunc(), fixed!(2e18));
+        assert_eq!(x.fract(), fixed!(0.5e18));
+        assert_eq!(x.round(HalfUp), fixed!(3e18));
+        assert_eq!(x.round(HalfDown), fixed!(2e18));
+        assert_eq!(x.round(HalfEven), fixed!(2e18));
+        assert_eq!(fixed_i128!(3.5e18).round(HalfEven), fixed!(4e18));
+
+        let x = fixed_i128!(-2.5e18);
+        assert_eq!(x.floor(), fixed!(-3e18));
+        assert_eq!(x.ceil(), fixed!(-2e18));
+        assert_eq!(x.trunc(), fixed!(-2e18));
+        assert_eq!(x.fract(), fixed!(-0.5e18));
+        assert_eq!(x.round(HalfUp), fixed!(-3e18));
+        assert_eq!(x.round(HalfDown), fixed!(-2e18));
+        assert_eq!(x.round(HalfEven), fixed!(-2e18));
+        assert_eq!(x.round(Up), fixed!(-3e18));
+        assert_eq!(x.round(Down), fixed!(-2e18));
+
+        let x = fixed_i256!(-0.1e18);
+        assert_eq!(x.floor(), fixed!(-1e18));
+        assert_eq!(x.ceil(), fixed!(0));
+        assert_eq!(x.fract(), x);
+
+        // Integers are unchanged.
+        let x = fixed_u256!(7e18);
+        assert_eq!(x.floor(), x);
+        assert_eq!(x.ceil(), x);
+        assert_eq!(x.fract(), fixed!(0));
+
+        // Other scales.
+        let x = FixedPoint::<U256, 6>::new(uint256!(1.5e6));
+        assert_eq!(x.floor().raw(), uint256!(1e6));
+        assert_eq!(x.ceil().raw(), uint256!(2e6));
+
+        // Rounding away from zero can overflow.
+        assert_eq!(
+            FixedPoint::<U256>::MAX.checked_round(Ceil),
+            Err(FixedPointError::Overflow)
+        );
+        assert_eq!(
+            FixedPoint::<I256>::MIN.checked_round(Floor),
+            Err(FixedPointError::Overflow)
+        );
+        assert!(std::panic::catch_unwind(|| FixedPoint::<i128>::MAX.ceil()).is_err());
+        assert_eq!(
+            FixedPoint::<I256>::MIN.trunc(),
+            FixedPoint::new(FixedPoint::<I256>::MIN.raw() / int256!(1e18) * int256!(1e18))
+        );
+    }
+
+    #[test]
+    fn test_to_integer() {
+        use RoundingMode::*;
+
+        assert_eq!(fixed_u256!(2.5e18).to_integer::<u128>(HalfUp), Ok(3));
+        assert_eq!(
+            fixed_u256!(2.5e18).to_integer::<U256>(Down),
+            Ok(uint256!(2))
+        );
+        assert_eq!(fixed_i128!(-2.5e18).to_integer::<i128>(Floor), Ok(-3));
+        assert_eq!(
+            fixed_i128!(-2.5e18).to_integer::<I256>(Ceil),
+            Ok(int256!(-2))
+        );
+        assert_eq!(fixed_i128!(-0.5e18).to_integer::<u128>(Ceil), Ok(0));
+        assert!(matches!(
+            fixed_i128!(-1.5e18).to_integer::<u128>(Ceil),
+            Err(FixedPointError::ConversionOutOfRange { to: "u128", .. })
+        ));
+        assert_eq!(
+            FixedPoint::<U256>::MAX.to_integer::<U256>(Down),
+            Ok(U256::MAX / uint256!(1e18))
+        );
+        assert!(FixedPoint::<U256, 0>::MAX.to_integer::<u128>(Down).is_err());
+    }
+}
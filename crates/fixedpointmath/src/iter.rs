@@ -0,0 +1,191 @@
+use std::{
+    borrow::Borrow,
+    iter::{Product, Sum},
+};
+
+use ethers::types::U512;
+
+use crate::{stats::WideSum, FixedPoint, FixedPointError, FixedPointValue, RoundingMode};
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Sums `values`, returning an error if any partial sum overflows. The sum
+    /// of no values is zero.
+    pub fn try_sum<I>(values: I) -> Result<Self, FixedPointError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        values
+            .into_iter()
+            .try_fold(Self::zero(), |sum, value| sum.checked_add(*value.borrow()))
+    }
+
+    /// Multiplies `values`, rounding each partial product down and returning
+    /// an error if any partial product overflows. The product of no values is
+    /// one.
+    pub fn try_product<I>(values: I) -> Result<Self, FixedPointError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        values.into_iter().try_fold(Self::ONE, |product, value| {
+            product.checked_mul_down(*value.borrow())
+        })
+    }
+
+    /// Sums `values` in a `U512` accumulator, returning an error only if the
+    /// final sum overflows. Unlike `try_sum`, partial sums may exceed the
+    /// bounds of `T`, e.g., when adding many large balances before
+    /// subtracting others.
+    pub fn sum_wide<I>(values: I) -> Result<Self, FixedPointError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        let mut sum = WideSum::default();
+        for value in values {
+            let value = value.borrow();
+            sum.add(value.sign(), U512::from(value.raw().unsigned_abs()))?;
+        }
+        sum.div(U512::one(), RoundingMode::Trunc)
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> Sum for FixedPoint<T, D> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, value| sum + value)
+    }
+}
+
+impl<'a, T: FixedPointValue, const D: u8> Sum<&'a Self> for FixedPoint<T, D> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> Product for FixedPoint<T, D> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |product, value| product * value)
+    }
+}
+
+impl<'a, T: FixedPointValue, const D: u8> Product<&'a Self> for FixedPoint<T, D> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use ethers::types::{I256, U256};
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::{fixed, fixed_i128, fixed_i256, fixed_u128, fixed_u256};
+
+    #[test]
+    fn test_sum() {
+        let values = [fixed_u256!(1.5e18), fixed!(2e18), fixed!(0.25e18)];
+        assert_eq!(values.iter().sum::<FixedPoint<U256>>(), fixed!(3.75e18));
+        assert_eq!(
+            values.into_iter().sum::<FixedPoint<U256>>(),
+            fixed!(3.75e18)
+        );
+        assert_eq!(FixedPoint::try_sum(values.iter()), Ok(fixed!(3.75e18)));
+        assert_eq!(FixedPoint::sum_wide(values), Ok(fixed!(3.75e18)));
+
+        let values = [fixed_i128!(-1e18), fixed!(2.5e18), fixed!(-4e18)];
+        assert_eq!(values.iter().sum::<FixedPoint<i128>>(), fixed!(-2.5e18));
+        assert_eq!(FixedPoint::try_sum(values), Ok(fixed!(-2.5e18)));
+        assert_eq!(FixedPoint::sum_wide(values.iter()), Ok(fixed!(-2.5e18)));
+
+        // Empty sums are zero.
+        assert_eq!(
+            FixedPoint::<U256>::try_sum::<[FixedPoint<U256>; 0]>([]),
+            Ok(fixed!(0))
+        );
+        assert_eq!(
+            FixedPoint::<I256>::sum_wide::<[FixedPoint<I256>; 0]>([]),
+            Ok(fixed!(0))
+        );
+        assert_eq!(
+            std::iter::empty::<FixedPoint<u128>>().sum::<FixedPoint<u128>>(),
+            fixed!(0)
+        );
+
+        // The wide sum only checks the final result.
+        let max = FixedPoint::<I256>::MAX;
+        let values = [max, max, -max, fixed!(-1e18)];
+        assert_eq!(FixedPoint::try_sum(values), Err(FixedPointError::Overflow));
+        assert_eq!(FixedPoint::sum_wide(values), Ok(max - fixed!(1e18)));
+        assert_eq!(
+            FixedPoint::sum_wide([max, fixed!(1)]),
+            Err(FixedPointError::Overflow)
+        );
+        assert_eq!(
+            FixedPoint::sum_wide([FixedPoint::<U256>::MAX; 3]),
+            Err(FixedPointError::Overflow)
+        );
+        assert!(panic::catch_unwind(|| [max, max].into_iter().sum::<FixedPoint<I256>>()).is_err());
+    }
+
+    #[test]
+    fn test_product() {
+        let values = [fixed_u256!(1.5e18), fixed!(2e18), fixed!(0.25e18)];
+        assert_eq!(values.iter().product::<FixedPoint<U256>>(), fixed!(0.75e18));
+        assert_eq!(FixedPoint::try_product(values), Ok(fixed!(0.75e18)));
+
+        let values = [fixed_i256!(-2e18), fixed!(3e18), fixed!(-0.5e18)];
+        assert_eq!(
+            values.into_iter().product::<FixedPoint<I256>>(),
+            fixed!(3e18)
+        );
+        assert_eq!(FixedPoint::try_product(values.iter()), Ok(fixed!(3e18)));
+
+        // Each partial product is rounded down.
+        let third = fixed_u128!(0.333333333333333333e18);
+        assert_eq!(
+            FixedPoint::try_product([third, third]),
+            Ok(fixed!(0.111111111111111110e18))
+        );
+
+        // Empty products are one.
+        assert_eq!(
+            std::iter::empty::<FixedPoint<U256>>().product::<FixedPoint<U256>>(),
+            fixed!(1e18)
+        );
+
+        // Overflow.
+        let max = FixedPoint::<u128>::MAX;
+        assert_eq!(
+            FixedPoint::try_product([max, fixed!(2e18)]),
+            Err(FixedPointError::Overflow)
+        );
+        assert!(panic::catch_unwind(|| [max, max].iter().product::<FixedPoint<u128>>()).is_err());
+    }
+
+    #[test]
+    fn fuzz_sum() {
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            let values = (0..rng.gen_range(0..=10))
+                .map(|_| rng.gen::<FixedPoint<i128>>())
+                .collect::<Vec<_>>();
+            let expected = values
+                .iter()
+                .map(|value| I256::from(value.raw()))
+                .fold(I256::zero(), |sum, value| sum + value);
+            let expected = i128::try_from(expected)
+                .map(FixedPoint::from)
+                .map_err(|_| FixedPointError::Overflow);
+
+            assert_eq!(FixedPoint::sum_wide(&values), expected, "{values:?}");
+            match FixedPoint::try_sum(&values) {
+                Ok(sum) => assert_eq!(Ok(sum), expected, "{values:?}"),
+                Err(err) => assert_eq!(err, FixedPointError::Overflow, "{values:?}"),
+            }
+        }
+    }
+}
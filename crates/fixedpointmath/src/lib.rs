@@ -8,31 +8,52 @@
 //!   type's limits.
 //! - Support for overflowing intermediate operations in `mul_div_down` and
 //!  `mul_div_up` via `U512`.
+//! - The number of decimals is a const generic parameter, e.g.,
+//!   `FixedPoint<U256, 6>`, which defaults to 18.
 //!
 //! Each of the functions is fuzz tested against the Solidity implementation to
 //! ensure that the behavior is identical given values bounded by the Solidity
 //! implementation's limits.
 
+mod constants;
+mod error;
 mod fixed_point;
+mod geometric_mean;
+mod integer;
+mod iter;
+mod log;
 mod macros;
 mod math;
+mod normal;
 mod rng;
+mod roots;
+mod rounding;
 mod sign;
+pub mod stats;
+mod transcendental;
 mod utils;
 mod value;
 mod value_impls;
 
+// Used by the expansion of the exported `fixed_point_value_impl!` macro.
+#[doc(hidden)]
+pub use constants::{constant_table, CONSTANT_COUNT};
+pub use error::*;
 pub use fixed_point::*;
 pub use rng::*;
+pub use rounding::*;
 pub use sign::*;
 pub use utils::*;
 pub use value::*;
 
 pub mod prelude {
     pub use super::{
+        error::FixedPointError,
         fixed, fixed_i128, fixed_i256,
         fixed_point::{Fixed, FixedPoint, ToFixed},
-        fixed_u128, fixed_u256, int256, uint256,
+        fixed_u128, fixed_u256, int256,
+        rounding::RoundingMode,
+        uint256,
         value::FixedPointValue,
     };
 }
@@ -0,0 +1,255 @@
+use ethers::types::{I256, U256, U512};
+
+use crate::{
+    exp, int256, ln, FixedPoint, FixedPointError, FixedPointSign, FixedPointValue, RoundingMode,
+};
+
+/// The scale of the values used by `ln` and `exp`.
+const WAD: u64 = 1_000_000_000_000_000_000;
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Computes the base-2 logarithm of self, returning an error if self isn't
+    /// positive or the result can't be represented by `T`, e.g., if the result
+    /// is negative and `T` is unsigned.
+    ///
+    /// The integer part of the result is exact, so the logarithm of a power of
+    /// two is exact.
+    pub fn log2(self) -> Result<Self, FixedPointError> {
+        Self::from_wad(self.log_wad(2, FixedPoint::LN_2)?, RoundingMode::Down)
+    }
+
+    /// Computes the base-10 logarithm of self, returning an error if self
+    /// isn't positive or the result can't be represented by `T`, e.g., if the
+    /// result is negative and `T` is unsigned.
+    ///
+    /// The integer part of the result is exact, so the logarithm of a power of
+    /// ten is exact.
+    pub fn log10(self) -> Result<Self, FixedPointError> {
+        Self::from_wad(self.log_wad(10, FixedPoint::LN_10)?, RoundingMode::Down)
+    }
+
+    /// Computes the logarithm of self in the given `base`, returning an error
+    /// if self or `base` isn't positive, `base` is one, or the result can't be
+    /// represented by `T`.
+    pub fn log(self, base: Self) -> Result<Self, FixedPointError> {
+        let one = U512::from(Self::ONE.raw().unsigned_abs());
+        let base_abs = U512::from(base.raw().unsigned_abs());
+        if base.is_positive() && base_abs == one * 2 {
+            return self.log2();
+        }
+        if base.is_positive() && base_abs == one * 10 {
+            return self.log10();
+        }
+
+        let log2_base = base.log_wad(2, FixedPoint::LN_2)?;
+        if log2_base.is_zero() {
+            return Err(FixedPointError::DivisionByZero);
+        }
+        Self::from_wad(
+            self.log_wad(2, FixedPoint::LN_2)? * int256!(1e18) / log2_base,
+            RoundingMode::Down,
+        )
+    }
+
+    /// Computes 2 raised to the power of self, returning an error if the
+    /// result overflows `T`.
+    ///
+    /// The integer part of self is applied exactly, so 2 raised to an integer
+    /// is exact as long as it can be represented at the scale of self.
+    pub fn exp2(self) -> Result<Self, FixedPointError> {
+        // Split self into an integer `k` and a fraction in `[0, 1)` so that
+        // `2^self = 2^fraction * 2^k`.
+        let k = self
+            .to_integer::<I256>(RoundingMode::Floor)
+            .map_err(|_| FixedPointError::Overflow)?;
+        if k >= int256!(256) {
+            return Err(FixedPointError::Overflow);
+        }
+        // Every `T` has fewer than 256 bits, so a scaled result below
+        // `2^-256` is zero.
+        if k < int256!(-256) {
+            return Ok(Self::zero());
+        }
+        let k = k.as_i32();
+
+        let one = Self::ONE.raw().unsigned_abs();
+        let rem = self.raw().unsigned_abs() % one;
+        let fraction = if self.is_negative() && !rem.is_zero() {
+            one - rem
+        } else {
+            rem
+        };
+
+        // Compute `2^fraction = exp(fraction * ln(2))` with 18 decimals.
+        let wad = U512::from(WAD);
+        let power = if fraction.is_zero() {
+            wad
+        } else {
+            let fraction = U256::try_from(fraction.full_mul(WAD.into()) / U512::from(one)).unwrap();
+            let exponent =
+                I256::from_raw(fraction) * FixedPoint::<I256>::LN_2.raw() / int256!(1e18);
+            U512::from(exp(exponent)?.into_raw())
+        };
+
+        // Scale the power of the fraction by `2^k`, converting it from 18
+        // decimals to the scale of self.
+        let abs = if k >= 0 {
+            (power << k)
+                .checked_mul(one.into())
+                .ok_or(FixedPointError::Overflow)?
+                / wad
+        } else {
+            power * U512::from(one) / (wad << -k)
+        };
+        let abs = U256::try_from(abs).map_err(|_| FixedPointError::Overflow)?;
+        Self::from_sign_and_abs(FixedPointSign::Positive, abs)
+            .map_err(|_| FixedPointError::Overflow)
+    }
+
+    /// Computes the logarithm of self in the integer `base` with 18 decimals.
+    ///
+    /// Self is split into `base^k * m` with `1 <= m < base`, so the integer
+    /// part `k` is exact and only `ln(m) / ln(base)` is approximated.
+    fn log_wad(self, base: u64, ln_base: FixedPoint<I256>) -> Result<I256, FixedPointError> {
+        if self.is_negative() || self.is_zero() {
+            return Err(FixedPointError::LnInvalidInput);
+        }
+
+        // Find `k` such that `1 <= numerator / denominator < base`, where
+        // `numerator / denominator = self / base^k`.
+        let base = U512::from(base);
+        let mut numerator = U512::from(self.raw().unsigned_abs());
+        let mut denominator = U512::from(Self::ONE.raw().unsigned_abs());
+        let mut k = 0_i64;
+        while numerator >= denominator * base {
+            denominator *= base;
+            k += 1;
+        }
+        while numerator < denominator {
+            numerator *= base;
+            k -= 1;
+        }
+
+        let integer = I256::from(k) * int256!(1e18);
+        if numerator == denominator {
+            return Ok(integer);
+        }
+
+        // The mantissa is in `[1, base)`, so it easily fits in an `I256`.
+        let mantissa = I256::from(
+            U256::try_from(numerator * U512::from(WAD) / denominator)
+                .unwrap()
+                .as_u128(),
+        );
+        Ok(integer + ln(mantissa)? * int256!(1e18) / ln_base.raw())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::{fixed, fixed_i128, fixed_i256, fixed_u128, fixed_u256, uint256};
+
+    #[test]
+    fn test_log() {
+        // Exact integer results.
+        assert_eq!(fixed_u256!(8e18).log2(), Ok(fixed!(3e18)));
+        assert_eq!(fixed_i256!(0.125e18).log2(), Ok(fixed!(-3e18)));
+        assert_eq!(fixed_u256!(1e18).log2(), Ok(fixed!(0)));
+        assert_eq!(fixed_u256!(1000e18).log10(), Ok(fixed!(3e18)));
+        assert_eq!(fixed_i128!(0.001e18).log10(), Ok(fixed!(-3e18)));
+        assert_eq!(fixed_i128!(1).log10(), Ok(fixed!(-18e18)));
+        assert_eq!(fixed_u256!(81e18).log(fixed!(3e18)), Ok(fixed!(4e18)));
+        assert_eq!(fixed_u256!(1024e18).log(fixed!(2e18)), Ok(fixed!(10e18)));
+        assert_eq!(
+            FixedPoint::<U256, 0>::MAX.log2(),
+            Ok(FixedPoint::new(uint256!(255)))
+        );
+
+        // Approximate results.
+        assert_eq!(
+            fixed_u256!(3e18).log2(),
+            Ok(fixed!(1.584962500721156181e18))
+        );
+        assert_eq!(
+            fixed_u256!(2e18).log10(),
+            Ok(fixed!(0.301029995663981195e18))
+        );
+        assert_eq!(fixed_i256!(0.5e18).log(fixed!(4e18)), Ok(fixed!(-0.5e18)));
+
+        // Invalid inputs.
+        assert_eq!(fixed_u256!(0).log2(), Err(FixedPointError::LnInvalidInput));
+        assert_eq!(
+            fixed_i128!(-1e18).log10(),
+            Err(FixedPointError::LnInvalidInput)
+        );
+        assert_eq!(
+            fixed_u256!(2e18).log(fixed!(1e18)),
+            Err(FixedPointError::DivisionByZero)
+        );
+        assert!(matches!(
+            fixed_u256!(0.5e18).log2(),
+            Err(FixedPointError::ConversionOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_exp2() {
+        // Exact integer results.
+        assert_eq!(fixed_u256!(10e18).exp2(), Ok(fixed!(1024e18)));
+        assert_eq!(fixed_i256!(-3e18).exp2(), Ok(fixed!(0.125e18)));
+        assert_eq!(fixed_u128!(0).exp2(), Ok(fixed!(1e18)));
+        assert_eq!(
+            FixedPoint::<U256, 0>::new(uint256!(255)).exp2(),
+            Ok(FixedPoint::new(U256::one() << 255))
+        );
+
+        // Approximate results.
+        assert_eq!(
+            fixed_u256!(0.5e18).exp2(),
+            Ok(fixed!(1.414213562373095047e18))
+        );
+        assert_eq!(
+            fixed_i256!(-0.5e18).exp2(),
+            Ok(fixed!(0.707106781186547523e18))
+        );
+
+        // Overflow and underflow.
+        assert_eq!(
+            FixedPoint::<U256, 0>::new(uint256!(256)).exp2(),
+            Err(FixedPointError::Overflow)
+        );
+        assert_eq!(fixed_i128!(128e18).exp2(), Err(FixedPointError::Overflow));
+        assert_eq!(fixed_i128!(-60e18).exp2(), Ok(fixed!(0)));
+        assert_eq!(FixedPoint::<i128>::MIN.exp2(), Ok(fixed!(0)));
+    }
+
+    fn to_f64<T: FixedPointValue, const D: u8>(x: FixedPoint<T, D>) -> f64 {
+        x.to_string().parse().unwrap()
+    }
+
+    #[test]
+    fn fuzz_log_exp2() {
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            // The logarithms are close to the float results.
+            let x = rng.gen_range(fixed_u256!(1)..=fixed!(1e36));
+            let actual = to_f64(x.log2().unwrap());
+            assert!((actual - to_f64(x).log2()).abs() < 1e-12, "log2({x})");
+            let actual = to_f64(x.log10().unwrap());
+            assert!((actual - to_f64(x).log10()).abs() < 1e-12, "log10({x})");
+
+            // Adding one to the exponent doubles the result.
+            let y = rng.gen_range(fixed_i256!(-10e18)..=fixed!(10e18));
+            let expected = y.exp2().unwrap().raw() * 2;
+            let actual = (y + fixed!(1e18)).exp2().unwrap().raw();
+            assert!((actual - expected).abs() <= 2.into(), "exp2({y})");
+
+            // log2 inverts exp2.
+            let actual = to_f64(y.exp2().unwrap().log2().unwrap());
+            assert!((actual - to_f64(y)).abs() < 1e-12, "log2(exp2({y}))");
+        }
+    }
+}
@@ -0,0 +1,541 @@
+use ethers::types::{I256, U256, U512};
+
+use crate::{
+    exp, int256, uint256, FixedPoint, FixedPointError, FixedPointSign, FixedPointValue,
+    RoundingMode,
+};
+
+/// `2 / √π` with 36 decimals.
+const TWO_OVER_SQRT_PI: u128 = 1_128_379_167_095_512_573_896_158_903_121_545_172;
+
+/// `1 / √(2π)` with 36 decimals.
+const INV_SQRT_2_PI: u128 = 398_942_280_401_432_677_939_946_059_934_381_868;
+
+/// The magnitude above which `erf` rounds to `±1`. `1 - erf(7)` is less than
+/// `1e-22`.
+const ERF_LIMIT: u64 = 7;
+
+/// The magnitude above which `norm_pdf` rounds to zero. `e^(-x^2 / 2)` is
+/// less than `1e-55`, which rounds to zero with 36 decimals, and `x^2 / 2`
+/// stays below the upper bound of `exp`'s domain, which is about `135.3`.
+const PDF_LIMIT: u64 = 16;
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Computes the error function of self, returning an error if the result
+    /// can't be represented by `T`, e.g., if it's negative and `T` is
+    /// unsigned.
+    ///
+    /// The result is computed from a series with 36 decimals and the `exp`
+    /// approximation, so it's within `1e-17` of the true value before it's
+    /// rounded toward zero at the scale of self.
+    pub fn erf(self) -> Result<Self, FixedPointError> {
+        let erf = match self.abs_36(ERF_LIMIT) {
+            Some(x) => erf_36(x)?,
+            None => FixedPoint::<U256, 36>::ONE.raw(),
+        };
+        Self::from_36(self.sign(), erf)
+    }
+
+    /// Computes the cumulative distribution function of the standard normal
+    /// distribution at self, i.e., the probability that a standard normal
+    /// random variable is at most self.
+    ///
+    /// The result is `(1 + erf(self / √2)) / 2`, so it's within `5e-18` of the
+    /// true value before it's rounded toward zero at the scale of self.
+    pub fn norm_cdf(self) -> Result<Self, FixedPointError> {
+        let one = FixedPoint::<U256, 36>::ONE.raw();
+        let erf = match self.abs_36(ERF_LIMIT * 2) {
+            Some(x) => {
+                let sqrt_2 = FixedPoint::<U256, 36>::SQRT_2.raw();
+                erf_36(U256::try_from(x.full_mul(one) / U512::from(sqrt_2)).unwrap())?
+            }
+            None => one,
+        };
+        let cdf = if self.is_negative() {
+            (one - erf) / 2
+        } else {
+            (one + erf) / 2
+        };
+        Self::from_36(FixedPointSign::Positive, cdf)
+    }
+
+    /// Computes the probability density function of the standard normal
+    /// distribution at self, i.e., `e^(-self^2 / 2) / √(2π)`.
+    ///
+    /// The result is within a relative error of `2e-17` of the true value
+    /// before it's rounded toward zero at the scale of self.
+    pub fn norm_pdf(self) -> Result<Self, FixedPointError> {
+        let Some(x) = self.abs_36(PDF_LIMIT) else {
+            return Ok(Self::zero());
+        };
+
+        // Dividing by `e^(x^2 / 2)`, which is at least one, keeps the relative
+        // error of `exp` small.
+        let half_x_squared = x.full_mul(x) / uint256!(2e54);
+        let exp_half_x_squared = exp(I256::from_raw(U256::try_from(half_x_squared).unwrap()))?;
+        let pdf = U512::from(INV_SQRT_2_PI) * U512::from(uint256!(1e18))
+            / U512::from(exp_half_x_squared.into_raw());
+        Self::from_36(FixedPointSign::Positive, U256::try_from(pdf).unwrap())
+    }
+
+    /// Computes the inverse of the cumulative distribution function of the
+    /// standard normal distribution at self, i.e., the value that a standard
+    /// normal random variable is at most with probability self. Returns an
+    /// error if self isn't in `(0, 1)`.
+    ///
+    /// The result is computed with Wichura's algorithm AS241 with 36
+    /// decimals, so it's within a relative error of `1e-16` of the true value
+    /// before it's rounded toward zero at the scale of self.
+    pub fn norm_inv_cdf(self) -> Result<Self, FixedPointError> {
+        let one = FixedPoint::<U256, 36>::ONE.raw();
+        let p = match self.abs_36(1) {
+            Some(p) if self.is_positive() && !p.is_zero() && p < one => p,
+            _ => return Err(FixedPointError::NormInvCdfInvalidInput),
+        };
+        let one = I256::from_raw(one);
+        let q = I256::from_raw(p) - one / 2;
+
+        // Close to the median, the result is a rational function of `q^2`.
+        if q.abs() <= int256!(0.425e36) {
+            let r = int256!(0.180625e36) - q * q / one;
+            let numerator = polynomial(
+                &[
+                    int256!(3.3871328727963666080e36),
+                    int256!(1.3314166789178437745e38),
+                    int256!(1.9715909503065514427e39),
+                    int256!(1.3731693765509461125e40),
+                    int256!(4.5921953931549871457e40),
+                    int256!(6.7265770927008700853e40),
+                    int256!(3.3430575583588128105e40),
+                    int256!(2.5090809287301226727e39),
+                ],
+                r,
+            );
+            let denominator = polynomial(
+                &[
+                    int256!(1e36),
+                    int256!(4.2313330701600911252e37),
+                    int256!(6.8718700749205790830e38),
+                    int256!(5.3941960214247511077e39),
+                    int256!(2.1213794301586595867e40),
+                    int256!(3.9307895800092710610e40),
+                    int256!(2.8729085735721942674e40),
+                    int256!(5.2264952788528545610e39),
+                ],
+                r,
+            );
+            let (sign, abs) = (q * numerator / denominator).into_sign_and_abs();
+            return Self::from_36(sign.into(), abs);
+        }
+
+        // In the tails, the result is a rational function of
+        // `sqrt(-ln(min(p, 1 - p)))`.
+        let tail = if q.is_negative() {
+            p
+        } else {
+            one.into_raw() - p
+        };
+        let ln_tail = FixedPoint::<U256, 36>::new(tail).ln_36()?;
+        let r = FixedPoint::<U256, 36>::new((-ln_tail).into_raw()).sqrt_down();
+        let r = I256::from_raw(r.raw());
+        let (numerator, denominator) = if r <= int256!(5e36) {
+            let r = r - int256!(1.6e36);
+            let numerator = polynomial(
+                &[
+                    int256!(1.42343711074968357734e36),
+                    int256!(4.63033784615654529590e36),
+                    int256!(5.76949722146069140550e36),
+                    int256!(3.64784832476320460504e36),
+                    int256!(1.27045825245236838258e36),
+                    int256!(2.41780725177450611770e35),
+                    int256!(2.27238449892691845833e34),
+                    int256!(7.74545014278341407640e32),
+                ],
+                r,
+            );
+            let denominator = polynomial(
+                &[
+                    int256!(1e36),
+                    int256!(2.05319162663775882187e36),
+                    int256!(1.67638483018380384940e36),
+                    int256!(6.89767334985100004550e35),
+                    int256!(1.48103976427480074590e35),
+                    int256!(1.51986665636164571966e34),
+                    int256!(5.47593808499534494600e32),
+                    int256!(1.05075007164441684324e27),
+                ],
+                r,
+            );
+            (numerator, denominator)
+        } else {
+            let r = r - int256!(5e36);
+            let numerator = polynomial(
+                &[
+                    int256!(6.65790464350110377720e36),
+                    int256!(5.46378491116411436990e36),
+                    int256!(1.78482653991729133580e36),
+                    int256!(2.96560571828504891230e35),
+                    int256!(2.65321895265761230930e34),
+                    int256!(1.24266094738807843860e33),
+                    int256!(2.71155556874348757815e31),
+                    int256!(2.01033439929228813265e29),
+                ],
+                r,
+            );
+            let denominator = polynomial(
+                &[
+                    int256!(1e36),
+                    int256!(5.99832206555887937690e35),
+                    int256!(1.36929880922735805310e35),
+                    int256!(1.48753612908506148525e34),
+                    int256!(7.86869131145613259100e32),
+                    int256!(1.84631831751005468180e31),
+                    int256!(1.42151175831644588870e29),
+                    int256!(2.04426310338993978564e21),
+                ],
+                r,
+            );
+            (numerator, denominator)
+        };
+        let sign = if q.is_negative() {
+            FixedPointSign::Negative
+        } else {
+            FixedPointSign::Positive
+        };
+        Self::from_36(sign, (numerator * one / denominator).into_raw())
+    }
+
+    /// Converts the magnitude of self to 36 decimals, rounding toward zero and
+    /// returning `None` if it's greater than `limit`.
+    fn abs_36(self, limit: u64) -> Option<U256> {
+        let abs = U512::from(self.raw().unsigned_abs());
+        let one = U512::from(Self::ONE.raw().unsigned_abs());
+        if abs > one * U512::from(limit) {
+            return None;
+        }
+        let abs_36 = abs * U512::from(FixedPoint::<U256, 36>::ONE.raw()) / one;
+        Some(U256::try_from(abs_36).unwrap())
+    }
+
+    /// Converts a magnitude with 36 decimals and a sign to the scale and type
+    /// of self, rounding toward zero.
+    fn from_36(sign: FixedPointSign, abs: U256) -> Result<Self, FixedPointError> {
+        let value = FixedPoint::<I256, 36>::from_sign_and_abs(sign, abs)?
+            .change_decimals::<D>(RoundingMode::Down)?;
+        Self::from_sign_and_abs(value.sign(), value.raw().unsigned_abs())
+    }
+}
+
+/// Computes the error function of `x >= 0` with 36 decimals as
+/// `2 / √π * x * e^(-x^2) * Σ (2x^2)^n / (1 * 3 * ... * (2n + 1))`.
+///
+/// Unlike the Taylor series of `erf`, the terms are all positive, so there's
+/// no cancellation. Dividing by `e^(x^2)`, which is at least one, keeps the
+/// relative error of `exp` small.
+fn erf_36(x: U256) -> Result<U256, FixedPointError> {
+    let one = U512::from(FixedPoint::<U256, 36>::ONE.raw());
+    let x = U512::from(x);
+    if x > one * U512::from(ERF_LIMIT) {
+        return Ok(FixedPoint::<U256, 36>::ONE.raw());
+    }
+
+    let x_squared = x * x / one;
+    let mut sum = one;
+    let mut term = one;
+    let mut n = 0_u64;
+    loop {
+        n += 1;
+        term = ((term * x_squared) << 1) / (one * U512::from(2 * n + 1));
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+    }
+
+    // `x^2` is at most 49, so it's well within the domain of `exp`.
+    let wad = U512::from(uint256!(1e18));
+    let exp_x_squared = exp(I256::from_raw(U256::try_from(x_squared / wad).unwrap()))?;
+    let erf =
+        sum * x * U512::from(TWO_OVER_SQRT_PI) / (one * wad * U512::from(exp_x_squared.into_raw()));
+    Ok(U256::try_from(erf.min(one)).unwrap())
+}
+
+/// Evaluates the polynomial with the given coefficients with 36 decimals,
+/// from the constant term up, at `x` with Horner's method.
+fn polynomial(coefficients: &[I256], x: I256) -> I256 {
+    let one = int256!(1e36);
+    coefficients
+        .iter()
+        .rev()
+        .fold(I256::zero(), |result, &coefficient| {
+            result * x / one + coefficient
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::{fixed, fixed_i128, fixed_i256, fixed_u256};
+
+    #[test]
+    fn test_erf() {
+        assert_eq!(fixed_i256!(0).erf(), Ok(fixed!(0)));
+        assert_eq!(fixed_i256!(1e18).erf(), Ok(fixed!(0.842700792949714869e18)));
+        assert_eq!(
+            fixed_i256!(-0.5e18).erf(),
+            Ok(fixed!(-0.520499877813046537e18))
+        );
+        assert_eq!(fixed_i128!(2e18).erf(), Ok(fixed!(0.995322265018952734e18)));
+        assert_eq!(
+            fixed_u256!(0.1e18).erf(),
+            Ok(fixed!(0.112462916018284892e18))
+        );
+        assert_eq!(fixed_i256!(10e18).erf(), Ok(fixed!(1e18)));
+        assert_eq!(FixedPoint::<i128>::MIN.erf(), Ok(fixed!(-1e18)));
+
+        // The result is rounded toward zero at the scale of self.
+        assert_eq!(
+            FixedPoint::<i128, 6>::new(500_000).erf(),
+            Ok(FixedPoint::new(520_499))
+        );
+        // With more decimals, the result is within `1e-17` of the true value.
+        let actual = FixedPoint::<I256, 36>::new(int256!(0.5e36)).erf().unwrap();
+        let expected = int256!(0.520499877813046537682746653891964528e36);
+        assert!((actual.raw() - expected).abs() <= int256!(1e19));
+    }
+
+    #[test]
+    fn test_norm() {
+        assert_eq!(fixed_i256!(0).norm_cdf(), Ok(fixed!(0.5e18)));
+        assert_eq!(
+            fixed_i256!(1e18).norm_cdf(),
+            Ok(fixed!(0.841344746068542948e18))
+        );
+        assert_eq!(
+            fixed_i128!(-1.96e18).norm_cdf(),
+            Ok(fixed!(0.024997895148220433e18))
+        );
+        assert_eq!(
+            fixed_u256!(3e18).norm_cdf(),
+            Ok(fixed!(0.998650101968369905e18))
+        );
+        assert_eq!(fixed_i256!(-40e18).norm_cdf(), Ok(fixed!(0)));
+        assert_eq!(fixed_i256!(40e18).norm_cdf(), Ok(fixed!(1e18)));
+
+        assert_eq!(
+            fixed_i256!(0).norm_pdf(),
+            Ok(fixed!(0.398942280401432677e18))
+        );
+        assert_eq!(
+            fixed_u256!(1e18).norm_pdf(),
+            Ok(fixed!(0.241970724519143349e18))
+        );
+        assert_eq!(
+            fixed_i128!(-2.5e18).norm_pdf(),
+            Ok(fixed!(0.017528300493568537e18))
+        );
+        assert_eq!(fixed_i256!(-20e18).norm_pdf(), Ok(fixed!(0)));
+        assert_eq!(fixed_i256!(16e18).norm_pdf(), Ok(fixed!(0)));
+        assert_eq!(fixed_i256!(16.5e18).norm_pdf(), Ok(fixed!(0)));
+        assert_eq!(fixed_i256!(-17e18).norm_pdf(), Ok(fixed!(0)));
+
+        assert_eq!(fixed_i256!(0.5e18).norm_inv_cdf(), Ok(fixed!(0)));
+        assert_eq!(
+            fixed_i256!(0.975e18).norm_inv_cdf(),
+            Ok(fixed!(1.959963984540054263e18))
+        );
+        assert_eq!(
+            fixed_i128!(0.025e18).norm_inv_cdf(),
+            Ok(fixed!(-1.959963984540054263e18))
+        );
+        assert_eq!(
+            fixed_i256!(0.000001e18).norm_inv_cdf(),
+            Ok(fixed!(-4.753424308822899014e18))
+        );
+
+        // Invalid inputs.
+        for p in [fixed_i256!(0), fixed!(1e18), fixed!(-0.5e18), fixed!(2e18)] {
+            assert_eq!(
+                p.norm_inv_cdf(),
+                Err(FixedPointError::NormInvCdfInvalidInput)
+            );
+        }
+        assert!(matches!(
+            fixed_u256!(0.1e18).norm_inv_cdf(),
+            Err(FixedPointError::ConversionOutOfRange { .. })
+        ));
+    }
+
+    /// The number of decimals of the reference values. The alternating
+    /// series of `erf` cancels about 85 digits for arguments up to 14, so it
+    /// needs many more decimals than the results.
+    const REFERENCE_DECIMALS: u32 = 160;
+
+    /// `2 / √π` with 70 decimals.
+    const TWO_OVER_SQRT_PI_70: &str =
+        "11283791670955125738961589031215451716881012586579977136881714434212849";
+
+    fn scale() -> BigInt {
+        BigInt::from(10).pow(REFERENCE_DECIMALS)
+    }
+
+    /// Converts a value with `D` decimals to a reference value.
+    fn to_reference<const D: u8>(x: FixedPoint<I256, D>) -> BigInt {
+        x.raw().to_string().parse::<BigInt>().unwrap()
+            * BigInt::from(10).pow(REFERENCE_DECIMALS - u32::from(D))
+    }
+
+    /// Computes `erf(x)` with the Taylor series
+    /// `2 / √π * Σ (-1)^n x^(2n + 1) / (n! (2n + 1))`.
+    fn erf_reference(x: &BigInt) -> BigInt {
+        let scale = scale();
+        let x_squared = x * x / &scale;
+        let mut power = x.clone();
+        let mut sum = BigInt::ZERO;
+        for n in 0_u32.. {
+            let term = &power / (2 * n + 1);
+            if term == BigInt::ZERO {
+                break;
+            }
+            if n % 2 == 0 {
+                sum += term;
+            } else {
+                sum -= term;
+            }
+            power = power * &x_squared / &scale / (n + 1);
+        }
+        sum * TWO_OVER_SQRT_PI_70.parse::<BigInt>().unwrap() / BigInt::from(10).pow(70)
+    }
+
+    /// Computes `e^x` for `x >= 0` with its Taylor series.
+    fn exp_reference(x: &BigInt) -> BigInt {
+        let scale = scale();
+        let mut term = scale.clone();
+        let mut sum = BigInt::ZERO;
+        for n in 1_u32.. {
+            if term == BigInt::ZERO {
+                break;
+            }
+            sum += &term;
+            term = term * x / &scale / n;
+        }
+        sum
+    }
+
+    /// Checks that `actual` is within `error` of `expected`, both with
+    /// `REFERENCE_DECIMALS` decimals, plus one unit in the last place at `D`
+    /// decimals for the final rounding.
+    fn assert_close<const D: u8>(actual: BigInt, expected: &BigInt, error: &BigInt, name: &str) {
+        let ulp = BigInt::from(10).pow(REFERENCE_DECIMALS - u32::from(D));
+        let difference = actual - expected;
+        assert!(
+            *difference.magnitude() <= error.magnitude() + ulp.magnitude(),
+            "{name}: off by {difference}"
+        );
+    }
+
+    fn fuzz_norm_bounds_for<const D: u8>() {
+        let scale = scale();
+        let sqrt_2 = BigInt::sqrt(&(&scale * &scale * 2_u8));
+        let inv_sqrt_2_pi = TWO_OVER_SQRT_PI_70.parse::<BigInt>().unwrap()
+            * BigInt::from(10).pow(REFERENCE_DECIMALS - 70)
+            * &scale
+            / (&sqrt_2 * 2);
+        let error = |e: i32| &scale / BigInt::from(10).pow(e.unsigned_abs());
+
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            // `erf` is within `1e-17`.
+            let one = FixedPoint::<I256, D>::ONE.raw();
+            let x = rng.gen_range(FixedPoint::<I256, D>::new(-one * 8)..=FixedPoint::new(one * 8));
+            let expected = erf_reference(&to_reference(x));
+            let actual = to_reference(x.erf().unwrap());
+            assert_close::<D>(actual, &expected, &error(-17), &format!("erf({x})"));
+
+            // `norm_cdf` is within `5e-18`.
+            let x =
+                rng.gen_range(FixedPoint::<I256, D>::new(-one * 20)..=FixedPoint::new(one * 20));
+            let erf = erf_reference(&(to_reference(x) * &scale / &sqrt_2));
+            let expected = (&scale + erf) / 2;
+            let actual = to_reference(x.norm_cdf().unwrap());
+            assert_close::<D>(
+                actual,
+                &expected,
+                &(error(-18) * 5),
+                &format!("norm_cdf({x})"),
+            );
+
+            // `norm_pdf` is within a relative error of `2e-17`.
+            let x_reference = to_reference(x);
+            let half_x_squared = &x_reference * &x_reference / &scale / 2;
+            let expected = &inv_sqrt_2_pi * &scale / exp_reference(&half_x_squared);
+            let actual = to_reference(x.norm_pdf().unwrap());
+            let relative_error = &expected * 2 / BigInt::from(10).pow(17);
+            assert_close::<D>(
+                actual,
+                &expected,
+                &relative_error,
+                &format!("norm_pdf({x})"),
+            );
+
+            // `norm_inv_cdf` is within a relative error of `1e-16`. The exact
+            // inverse is one Newton step away from the result, since the
+            // correction is tiny.
+            let p = rng.gen_range(FixedPoint::<I256, D>::new(1)..FixedPoint::ONE);
+            let actual = p.norm_inv_cdf().unwrap();
+            let y = to_reference(actual);
+            let erf = erf_reference(&(&y * &scale / &sqrt_2));
+            let cdf = (&scale + erf) / 2;
+            let half_y_squared = &y * &y / &scale / 2;
+            let pdf = &inv_sqrt_2_pi * &scale / exp_reference(&half_y_squared);
+            let expected = &y - (cdf - to_reference(p)) * &scale / pdf;
+            let relative_error = &expected / BigInt::from(10).pow(16);
+            assert_close::<D>(y, &expected, &relative_error, &format!("norm_inv_cdf({p})"));
+        }
+    }
+
+    #[test]
+    fn fuzz_norm_bounds() {
+        fuzz_norm_bounds_for::<18>();
+        fuzz_norm_bounds_for::<36>();
+    }
+
+    #[test]
+    fn fuzz_norm() {
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            let x = rng.gen_range(fixed_i256!(-20e18)..=fixed!(20e18));
+
+            // erf is odd and the CDF is symmetric about the median.
+            assert_eq!(x.erf().unwrap(), -(-x).erf().unwrap(), "erf({x})");
+            let sum = x.norm_cdf().unwrap() + (-x).norm_cdf().unwrap();
+            assert!(
+                (sum - fixed!(1e18)).raw().abs() <= int256!(1),
+                "norm_cdf({x})"
+            );
+            assert_eq!(x.norm_pdf().unwrap(), (-x).norm_pdf().unwrap());
+            assert!(x.norm_pdf().unwrap() <= fixed!(0.398942280401432677e18));
+
+            // The inverse CDF inverts the CDF as long as the CDF is far
+            // enough from zero and one to keep the precision.
+            let x = rng.gen_range(fixed_i256!(-3e18)..=fixed!(3e18));
+            let actual = x.norm_cdf().unwrap().norm_inv_cdf().unwrap();
+            assert!(
+                (actual - x).raw().abs() <= int256!(1e6),
+                "norm_inv_cdf(norm_cdf({x})) = {actual}"
+            );
+
+            // The same holds with 36 decimals.
+            let x =
+                FixedPoint::<i128, 36>::new(rng.gen_range(-(10_i128.pow(36))..=10_i128.pow(36)));
+            let actual = x.norm_cdf().unwrap().norm_inv_cdf().unwrap();
+            assert!(
+                (actual - x).raw().abs() <= 10_i128.pow(21),
+                "norm_inv_cdf(norm_cdf({x})) = {actual}"
+            );
+        }
+    }
+}
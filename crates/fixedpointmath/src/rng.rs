@@ -12,17 +12,17 @@ use crate::{FixedPoint, FixedPointValue};
 // https://docs.rs/rand/latest/rand/distributions/uniform/index.html#extending-uniform-to-support-a-custom-type
 
 #[derive(Clone, Copy, Debug)]
-pub struct UniformFixedPoint<T: FixedPointValue> {
-    low: FixedPoint<T>,
-    high: FixedPoint<T>,
+pub struct UniformFixedPoint<T: FixedPointValue, const D: u8> {
+    low: FixedPoint<T, D>,
+    high: FixedPoint<T, D>,
 }
 
-impl<T: FixedPointValue> SampleUniform for FixedPoint<T> {
-    type Sampler = UniformFixedPoint<T>;
+impl<T: FixedPointValue, const D: u8> SampleUniform for FixedPoint<T, D> {
+    type Sampler = UniformFixedPoint<T, D>;
 }
 
-impl<T: FixedPointValue> UniformSampler for UniformFixedPoint<T> {
-    type X = FixedPoint<T>;
+impl<T: FixedPointValue, const D: u8> UniformSampler for UniformFixedPoint<T, D> {
+    type X = FixedPoint<T, D>;
 
     #[inline]
     fn new<B1, B2>(low_b: B1, high_b: B2) -> Self
@@ -61,14 +61,14 @@ impl<T: FixedPointValue> UniformSampler for UniformFixedPoint<T> {
     }
 
     #[inline]
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FixedPoint<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FixedPoint<T, D> {
         let size = self.high.abs_diff(self.low);
         if size.is_zero() {
             panic!("UniformFixedPoint::sample called with size zero.");
         }
         let value = FixedPoint::new(rng.gen::<[u8; 32]>());
         let narrowed = value % size;
-        let max = FixedPoint::<T>::MAX;
+        let max = FixedPoint::<T, D>::MAX;
         let raw = if narrowed <= max.unsigned_abs() {
             self.low.raw() + T::from_u256(narrowed.raw()).unwrap()
         } else {
@@ -80,9 +80,9 @@ impl<T: FixedPointValue> UniformSampler for UniformFixedPoint<T> {
     }
 }
 
-impl<T: FixedPointValue> Distribution<FixedPoint<T>> for Standard {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FixedPoint<T> {
-        rng.gen_range(FixedPoint::<T>::MIN..=FixedPoint::<T>::MAX)
+impl<T: FixedPointValue, const D: u8> Distribution<FixedPoint<T, D>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FixedPoint<T, D> {
+        rng.gen_range(FixedPoint::<T, D>::MIN..=FixedPoint::<T, D>::MAX)
     }
 }
 
@@ -102,7 +102,7 @@ mod tests {
 
         // new
         assert!(
-            std::panic::catch_unwind(|| { UniformFixedPoint::new(low, high + 1.into()) }).is_err()
+            std::panic::catch_unwind(|| { UniformFixedPoint::new(low, high + fixed!(1)) }).is_err()
         );
 
         // new_inclusive
@@ -0,0 +1,355 @@
+use ethers::types::U256;
+use num_bigint::BigUint;
+
+use crate::{FixedPoint, FixedPointError, FixedPointSign, FixedPointValue, RoundingMode};
+
+/// The largest degree of a root that's computed exactly. The radicand of an
+/// `n`th root has about `n` times as many digits as the root, and the cost of
+/// the Newton iterations grows faster than quadratically with its size.
+pub(crate) const MAX_ROOT_DEGREE: u32 = 1_000;
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Computes the square root of self, rounding down.
+    ///
+    /// # Panics
+    ///
+    /// If self is negative.
+    pub fn sqrt_down(self) -> Self {
+        self.root(2, RoundingMode::Down)
+    }
+
+    /// Computes the square root of self, rounding up.
+    ///
+    /// # Panics
+    ///
+    /// If self is negative.
+    pub fn sqrt_up(self) -> Self {
+        self.root(2, RoundingMode::Up)
+    }
+
+    /// Computes the cube root of self, rounding toward zero. Unlike the square
+    /// root, the cube root of a negative value is defined and negative.
+    pub fn cbrt(self) -> Self {
+        self.root(3, RoundingMode::Down)
+    }
+
+    /// Computes the `n`th root of self, rounding toward zero.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero or greater than 1,000, or `n` is even and self is
+    /// negative.
+    pub fn nth_root(self, n: u32) -> Self {
+        self.root(n, RoundingMode::Down)
+    }
+
+    /// Computes the `n`th root of self, rounding the result according to
+    /// `mode`.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero or greater than 1,000, or `n` is even and self is
+    /// negative.
+    pub fn root(self, n: u32, mode: RoundingMode) -> Self {
+        self.checked_root(n, mode)
+            .unwrap_or_else(|err| panic!("{err}: root({self}, {n}, {mode:?})"))
+    }
+
+    /// Computes the `n`th root of self, rounding the result according to
+    /// `mode` and returning an error if `n` is zero or greater than 1,000, or
+    /// `n` is even and self is negative.
+    ///
+    /// The root is computed exactly with integer Newton iterations rather than
+    /// with `pow`, so the result is correctly rounded at the scale of self.
+    /// The exact radicand grows with `n`, so `n` is bounded to keep the cost
+    /// reasonable. Use `pow` with `1 / n` for larger degrees.
+    pub fn checked_root(self, n: u32, mode: RoundingMode) -> Result<Self, FixedPointError> {
+        if n == 0 || n > MAX_ROOT_DEGREE || (n % 2 == 0 && self.is_negative()) {
+            return Err(FixedPointError::RootInvalidInput);
+        }
+
+        // The root of `raw / ONE` at the scale of `ONE` is the integer root of
+        // `raw * ONE^(n - 1)`, which can be much larger than a `U512`.
+        let radicand = to_biguint(self.raw().unsigned_abs())
+            * to_biguint(Self::ONE.raw().unsigned_abs()).pow(n - 1);
+        let root = rounded_root(&radicand, n, self.sign(), mode);
+
+        // The root is between self and one, so it always fits in `T`.
+        let abs = U256::from_little_endian(&root.to_bytes_le());
+        Ok(Self::from_sign_and_abs(self.sign(), abs).unwrap())
+    }
+}
+
+/// Converts a `U256` to an arbitrary precision `BigUint`.
+pub(crate) fn to_biguint(value: U256) -> BigUint {
+    let mut bytes = [0; 32];
+    value.to_little_endian(&mut bytes);
+    BigUint::from_bytes_le(&bytes)
+}
+
+/// Computes the integer `n`th root of `radicand`, i.e., the largest `root`
+/// such that `root^n <= radicand`, with Newton iterations.
+fn integer_root(radicand: &BigUint, n: u32) -> BigUint {
+    if n == 1 || radicand.bits() <= 1 {
+        return radicand.clone();
+    }
+
+    // Estimate the root from the leading bits of the radicand as a float,
+    // then add a margin so that the estimate is above the root. Starting
+    // close to the root keeps the number of iterations small for large `n`,
+    // where each iteration costs an exponentiation.
+    let shift = radicand.bits().saturating_sub(64);
+    let leading = (radicand >> shift).to_u64_digits()[0] as f64;
+    let log2 = (leading.log2() + shift as f64) / n as f64;
+    let exponent = log2.floor() as u64;
+    let mantissa = BigUint::from((2_f64.powf(log2 - log2.floor()) * 2_f64.powi(52)) as u64);
+    let estimate = if exponent >= 52 {
+        mantissa << (exponent - 52)
+    } else {
+        mantissa >> (52 - exponent)
+    };
+    let mut root = &estimate + (&estimate >> 32_u8) + 1_u8;
+    while &root.pow(n) < radicand {
+        root <<= 1_u8;
+    }
+
+    // From above the root, `root - (root^n - radicand) / (n * root^(n - 1))`
+    // decreases until it reaches the integer root.
+    let n_minus_one = BigUint::from(n - 1);
+    loop {
+        let next = (&root * &n_minus_one + radicand / root.pow(n - 1)) / n;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+/// Computes the `n`th root of `radicand`, rounding according to `mode` as if
+/// the root had the given `sign`.
+pub(crate) fn rounded_root(
+    radicand: &BigUint,
+    n: u32,
+    sign: FixedPointSign,
+    mode: RoundingMode,
+) -> BigUint {
+    let root = integer_root(radicand, n);
+    let rounds_up = &root.pow(n) != radicand
+        && match mode {
+            RoundingMode::Down | RoundingMode::Trunc => false,
+            RoundingMode::Up | RoundingMode::Expand => true,
+            RoundingMode::Floor => sign.is_negative(),
+            RoundingMode::Ceil => sign.is_positive(),
+            // The root is past halfway to the next integer if
+            // `(root + 1/2)^n < radicand`. It's never exactly halfway since
+            // `(2 * root + 1)^n` is odd, so ties don't need to be handled.
+            RoundingMode::HalfUp | RoundingMode::HalfDown | RoundingMode::HalfEven => {
+                ((&root << 1_u8) + 1_u8).pow(n) < radicand << n
+            }
+        };
+    if rounds_up {
+        root + 1_u8
+    } else {
+        root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use ethers::types::{I256, U256};
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::{fixed, fixed_i128, fixed_i256, fixed_u128, fixed_u256, int256, uint256};
+
+    #[test]
+    fn test_roots() {
+        use RoundingMode::*;
+
+        assert_eq!(fixed_u256!(4e18).sqrt_down(), fixed!(2e18));
+        assert_eq!(fixed_u256!(4e18).sqrt_up(), fixed!(2e18));
+        assert_eq!(
+            fixed_u256!(2e18).sqrt_down(),
+            fixed!(1.414213562373095048e18)
+        );
+        assert_eq!(fixed_u256!(2e18).sqrt_up(), fixed!(1.414213562373095049e18));
+        assert_eq!(
+            fixed_u256!(2e18).root(2, HalfUp),
+            fixed!(1.414213562373095049e18)
+        );
+        assert_eq!(
+            fixed_u256!(3e18).root(2, HalfEven),
+            fixed!(1.732050807568877294e18)
+        );
+        assert_eq!(fixed_u256!(0.25e18).sqrt_down(), fixed!(0.5e18));
+        assert_eq!(fixed_u128!(0).sqrt_up(), fixed!(0));
+        assert_eq!(fixed_u128!(1).sqrt_down(), fixed!(1e9));
+
+        assert_eq!(fixed_i128!(27e18).cbrt(), fixed!(3e18));
+        assert_eq!(fixed_i128!(-27e18).cbrt(), fixed!(-3e18));
+        assert_eq!(fixed_i128!(2e18).cbrt(), fixed!(1.259921049894873164e18));
+        assert_eq!(
+            fixed_i128!(-2e18).root(3, Floor),
+            fixed!(-1.259921049894873165e18)
+        );
+        assert_eq!(
+            fixed_i128!(-2e18).root(3, Ceil),
+            fixed!(-1.259921049894873164e18)
+        );
+
+        assert_eq!(fixed_u256!(1024e18).nth_root(10), fixed!(2e18));
+        assert_eq!(fixed_u256!(5e18).nth_root(1), fixed!(5e18));
+        assert_eq!(fixed_i256!(-32e18).nth_root(5), fixed!(-2e18));
+
+        // Other scales.
+        let x = FixedPoint::<U256, 6>::new(uint256!(2e6));
+        assert_eq!(x.sqrt_down().raw(), uint256!(1.414213e6));
+        assert_eq!(x.sqrt_up().raw(), uint256!(1.414214e6));
+
+        // Invalid inputs.
+        assert_eq!(
+            fixed_i128!(-1e18).checked_root(2, Down),
+            Err(FixedPointError::RootInvalidInput)
+        );
+        assert_eq!(
+            fixed_u256!(1e18).checked_root(0, Down),
+            Err(FixedPointError::RootInvalidInput)
+        );
+        assert_eq!(
+            FixedPoint::<U256, 0>::new(uint256!(2)).checked_root(2, Down),
+            Ok(FixedPoint::new(uint256!(1)))
+        );
+        assert_eq!(
+            fixed_u256!(1e18).checked_root(1_001, Down),
+            Err(FixedPointError::RootInvalidInput)
+        );
+        assert_eq!(
+            fixed_u256!(2e18).checked_root(u32::MAX, Down),
+            Err(FixedPointError::RootInvalidInput)
+        );
+        assert!(panic::catch_unwind(|| fixed_i128!(-4e18).sqrt_down()).is_err());
+
+        // The largest degree.
+        assert_eq!(
+            fixed_u256!(2e18).checked_root(1_000, Up),
+            Ok(fixed!(1.000693387462580633e18))
+        );
+
+        // Large values.
+        assert_eq!(
+            FixedPoint::<U256>::MAX.sqrt_down(),
+            FixedPoint::new(uint256!(340282366920938463463374607431768211455999999999))
+        );
+        assert_eq!(
+            FixedPoint::<I256>::MIN.cbrt(),
+            -FixedPoint::new(int256!(38685626227668133590597632e12))
+        );
+    }
+
+    /// Checks that `root` is the `n`th root of `x` rounded according to
+    /// `mode` using only multiplication: the magnitude of the true root is
+    /// between `root - 1` and `root + 1` in units of the last place, on the
+    /// side given by `mode`.
+    fn check_root<T: FixedPointValue, const D: u8>(
+        x: FixedPoint<T, D>,
+        n: u32,
+        mode: RoundingMode,
+        root: Result<FixedPoint<T, D>, FixedPointError>,
+    ) {
+        if n == 0 || (n % 2 == 0 && x.is_negative()) {
+            assert_eq!(root, Err(FixedPointError::RootInvalidInput));
+            return;
+        }
+        let root = root.unwrap();
+        assert!(root.is_zero() || root.sign() == x.sign(), "root({x}, {n})");
+
+        // Compare `(2 * root + k)^n` to `(2 * |root(x)|)^n`, which is
+        // `2^n * |x| * 10^(D * (n - 1))` in units of the last place.
+        let exact = (x
+            .raw()
+            .unsigned_abs()
+            .to_string()
+            .parse::<BigUint>()
+            .unwrap()
+            * BigUint::from(10_u8).pow(u32::from(D) * (n - 1)))
+            << n;
+        let twice_root = root
+            .raw()
+            .unsigned_abs()
+            .to_string()
+            .parse::<BigUint>()
+            .unwrap()
+            << 1_u8;
+        let power = |k: i8| -> Option<BigUint> {
+            let is_negative = k < 0;
+            let k = BigUint::from(k.unsigned_abs());
+            let value = if is_negative {
+                if twice_root < k {
+                    return None;
+                }
+                &twice_root - k
+            } else {
+                &twice_root + k
+            };
+            Some(value.pow(n))
+        };
+        // Whether `(2 * root + k)^n` is below the exact value, treating
+        // negative bases as below.
+        let below = |k: i8| !matches!(power(k), Some(pow) if pow >= exact);
+        let rounds_up = match mode {
+            RoundingMode::Down | RoundingMode::Trunc => Some(false),
+            RoundingMode::Up | RoundingMode::Expand => Some(true),
+            RoundingMode::Floor => Some(x.is_negative()),
+            RoundingMode::Ceil => Some(!x.is_negative()),
+            _ => None,
+        };
+        let is_rounded = match rounds_up {
+            // `root <= |root(x)| < root + 1`.
+            Some(false) => power(0).unwrap() <= exact && !below(2),
+            // `root - 1 < |root(x)| <= root`.
+            Some(true) => below(-2) && !below(0),
+            // `root - 1/2 < |root(x)| < root + 1/2`. The exact value is never
+            // halfway since `(2 * root + 1)^n` is odd.
+            None => below(-1) && !below(1) && power(1).unwrap() != exact,
+        };
+        assert!(is_rounded, "root({x}, {n}, {mode:?}) = {root}");
+    }
+
+    fn fuzz_root_for<T: FixedPointValue, const D: u8>() {
+        use RoundingMode::*;
+
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            let abs = U256::from(rng.gen::<[u8; 32]>()) >> rng.gen_range(0..256);
+            let sign = if T::is_signed() && rng.gen() {
+                FixedPointSign::Negative
+            } else {
+                FixedPointSign::Positive
+            };
+            let Ok(x) = FixedPoint::<T, D>::from_sign_and_abs(sign, abs) else {
+                continue;
+            };
+            let n = if rng.gen_ratio(1, 200) {
+                rng.gen_range(9..=MAX_ROOT_DEGREE)
+            } else {
+                rng.gen_range(0..=8)
+            };
+            for mode in [Down, Up, Floor, Ceil, HalfUp, HalfDown, HalfEven] {
+                check_root(x, n, mode, x.checked_root(n, mode));
+            }
+        }
+    }
+
+    #[test]
+    fn fuzz_root() {
+        fuzz_root_for::<U256, 18>();
+        fuzz_root_for::<I256, 18>();
+        fuzz_root_for::<u128, 18>();
+        fuzz_root_for::<i128, 18>();
+        fuzz_root_for::<U256, 6>();
+        fuzz_root_for::<I256, 0>();
+        fuzz_root_for::<U256, 77>();
+    }
+}
@@ -0,0 +1,90 @@
+use ethers::types::U512;
+
+use crate::FixedPointSign;
+
+/// The direction to round the result of an operation that can't be
+/// represented exactly at the result's scale.
+///
+/// `Down` and `Up` match the `*Down` and `*Up` functions of the Solidity
+/// library, which round the magnitude of the result, so negative values round
+/// toward and away from zero respectively. The remaining modes match the
+/// `roundingMode` options of `Intl.NumberFormat`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round toward zero, decreasing the magnitude. Equivalent to `Trunc`.
+    Down,
+    /// Round away from zero, increasing the magnitude. Equivalent to
+    /// `Expand`.
+    Up,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round to the nearest value, with ties rounding away from zero.
+    HalfUp,
+    /// Round to the nearest value, with ties rounding toward zero.
+    HalfDown,
+    /// Round to the nearest value, with ties rounding toward the nearest even
+    /// value.
+    HalfEven,
+    /// Round away from zero, increasing the magnitude. Equivalent to `Up`.
+    Expand,
+    /// Round toward zero, decreasing the magnitude. Equivalent to `Down`.
+    Trunc,
+}
+
+impl RoundingMode {
+    /// The mode that rounds the magnitude of a result with the given `sign` in
+    /// the same direction as self, i.e., `Floor` and `Ceil` are replaced by
+    /// `Down` or `Up`.
+    pub(crate) fn for_magnitude(self, sign: FixedPointSign) -> Self {
+        match self {
+            RoundingMode::Floor if sign.is_negative() => RoundingMode::Up,
+            RoundingMode::Floor => RoundingMode::Down,
+            RoundingMode::Ceil if sign.is_negative() => RoundingMode::Down,
+            RoundingMode::Ceil => RoundingMode::Up,
+            mode => mode,
+        }
+    }
+
+    /// The mode that rounds in the opposite direction of self. The modes that
+    /// round to the nearest value are unchanged.
+    pub(crate) fn reverse(self) -> Self {
+        match self {
+            RoundingMode::Down => RoundingMode::Up,
+            RoundingMode::Up => RoundingMode::Down,
+            RoundingMode::Floor => RoundingMode::Ceil,
+            RoundingMode::Ceil => RoundingMode::Floor,
+            RoundingMode::Expand => RoundingMode::Trunc,
+            RoundingMode::Trunc => RoundingMode::Expand,
+            mode => mode,
+        }
+    }
+
+    /// Whether a quotient with the given `sign` and truncated magnitude
+    /// `abs` should round away from zero, i.e., have its magnitude increased by
+    /// one, given the remainder `rem` of the division by `divisor`.
+    pub(crate) fn rounds_away_from_zero(
+        self,
+        sign: FixedPointSign,
+        abs_is_odd: bool,
+        rem: U512,
+        divisor: U512,
+    ) -> bool {
+        if rem.is_zero() {
+            return false;
+        }
+
+        // Compare the remainder to half of the divisor without overflowing.
+        let half = rem.cmp(&(divisor - rem));
+        match self {
+            RoundingMode::Down | RoundingMode::Trunc => false,
+            RoundingMode::Up | RoundingMode::Expand => true,
+            RoundingMode::Floor => sign.is_negative(),
+            RoundingMode::Ceil => sign.is_positive(),
+            RoundingMode::HalfUp => half.is_ge(),
+            RoundingMode::HalfDown => half.is_gt(),
+            RoundingMode::HalfEven => half.is_gt() || (half.is_eq() && abs_is_odd),
+        }
+    }
+}
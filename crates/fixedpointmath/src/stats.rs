@@ -0,0 +1,549 @@
+//! Statistics over slices of `FixedPoint` values.
+//!
+//! Sums are accumulated in `U512`, so long series can't overflow, and each
+//! result is rounded once according to an explicit `RoundingMode`.
+
+use ethers::types::{U256, U512};
+
+use crate::{FixedPoint, FixedPointError, FixedPointSign, FixedPointValue, RoundingMode};
+
+/// Computes the arithmetic mean of `values`, rounding according to `mode`.
+/// Returns an error if `values` is empty.
+pub fn mean<T: FixedPointValue, const D: u8>(
+    values: &[FixedPoint<T, D>],
+    mode: RoundingMode,
+) -> Result<FixedPoint<T, D>, FixedPointError> {
+    if values.is_empty() {
+        return Err(FixedPointError::StatsInvalidInput);
+    }
+    let mut sum = WideSum::default();
+    for value in values {
+        sum.add(value.sign(), value.raw().unsigned_abs().into())?;
+    }
+    sum.div(values.len().into(), mode)
+}
+
+/// Computes the mean of `(value, weight)` pairs weighted by their weights,
+/// `Σ w * x / Σ w`, rounding according to `mode`. Returns an error if
+/// `values` is empty, contains a negative weight, or its weights sum to zero.
+pub fn weighted_mean<T: FixedPointValue, const D: u8>(
+    values: &[(FixedPoint<T, D>, FixedPoint<T, D>)],
+    mode: RoundingMode,
+) -> Result<FixedPoint<T, D>, FixedPointError> {
+    let mut sum = WideSum::default();
+    let mut total_weight = U512::zero();
+    for (value, weight) in values {
+        if weight.is_negative() {
+            return Err(FixedPointError::StatsInvalidInput);
+        }
+        let weight = weight.raw().unsigned_abs();
+        total_weight += U512::from(weight);
+        sum.add(value.sign(), value.raw().unsigned_abs().full_mul(weight))?;
+    }
+    if total_weight.is_zero() {
+        return Err(FixedPointError::StatsInvalidInput);
+    }
+    sum.div(total_weight, mode)
+}
+
+/// Computes the population variance of `values`, the mean of the squared
+/// deviations from their mean, rounding according to `mode`. Returns an error
+/// if `values` is empty or the sum of the squared deviations overflows a
+/// `U512`.
+///
+/// The variance is computed exactly from the exact mean, so the result is
+/// correctly rounded.
+pub fn variance<T: FixedPointValue, const D: u8>(
+    values: &[FixedPoint<T, D>],
+    mode: RoundingMode,
+) -> Result<FixedPoint<T, D>, FixedPointError> {
+    if values.is_empty() {
+        return Err(FixedPointError::StatsInvalidInput);
+    }
+    let n = U512::from(values.len());
+
+    // Split the sum into `n * m + r` where `m` is the floor of the mean and
+    // `0 <= r < n`.
+    let mut sum = WideSum::default();
+    for value in values {
+        sum.add(value.sign(), value.raw().unsigned_abs().into())?;
+    }
+    let (sign, abs) = sum.into_sign_and_abs();
+    let (mut m, mut r) = abs.div_mod(n);
+    if sign.is_negative() && !r.is_zero() {
+        m += U512::one();
+        r = n - r;
+    }
+    let m_sign = if m.is_zero() {
+        FixedPointSign::Positive
+    } else {
+        sign
+    };
+
+    // The deviations from `m` are at most the range of the values, so their
+    // squares fit in a `U512`.
+    let mut squares = U512::zero();
+    for value in values {
+        let abs = U512::from(value.raw().unsigned_abs());
+        let deviation = if value.sign() != m_sign {
+            abs + m
+        } else if abs >= m {
+            abs - m
+        } else {
+            m - abs
+        };
+        squares = squares
+            .checked_add(deviation * deviation)
+            .ok_or(FixedPointError::Overflow)?;
+    }
+
+    // Since `Σ (x - μ)^2 = Σ (x - m)^2 - r^2 / n`, the variance is
+    // `(n * Σ (x - m)^2 - r^2) / (n^2 * ONE)` at the scale of `ONE`. Splitting
+    // off the integer part first keeps the intermediate values in a `U512`.
+    let one = U512::from(FixedPoint::<T, D>::ONE.raw().unsigned_abs());
+    let (mut quotient, remainder) = squares.div_mod(n * one);
+    let denominator = n * n * one;
+    let mut fraction = remainder * n;
+    let r_squared = r * r;
+    if fraction < r_squared {
+        quotient -= U512::one();
+        fraction += denominator;
+    }
+    fraction -= r_squared;
+
+    let mut abs = U256::try_from(quotient).map_err(|_| FixedPointError::Overflow)?;
+    if mode.rounds_away_from_zero(FixedPointSign::Positive, abs.bit(0), fraction, denominator) {
+        abs = abs
+            .checked_add(U256::one())
+            .ok_or(FixedPointError::Overflow)?;
+    }
+    FixedPoint::from_sign_and_abs(FixedPointSign::Positive, abs)
+        .map_err(|_| FixedPointError::Overflow)
+}
+
+/// Computes the population standard deviation of `values`, the square root
+/// of their variance, rounding according to `mode`. Returns an error if
+/// `values` is empty or the variance overflows.
+///
+/// The variance is rounded before its root is taken, so the result isn't
+/// always correctly rounded, but rounding down or up still gives a lower or
+/// upper bound. When the variance is at least one, the result is at most one
+/// unit in the last place further in the direction of `mode` than the
+/// correctly rounded value.
+pub fn std_dev<T: FixedPointValue, const D: u8>(
+    values: &[FixedPoint<T, D>],
+    mode: RoundingMode,
+) -> Result<FixedPoint<T, D>, FixedPointError> {
+    variance(values, mode)?.checked_root(2, mode)
+}
+
+/// Computes the median of `values`, rounding the mean of the two middle
+/// values of an even number of values according to `mode`. Returns an error
+/// if `values` is empty.
+pub fn median<T: FixedPointValue, const D: u8>(
+    values: &[FixedPoint<T, D>],
+    mode: RoundingMode,
+) -> Result<FixedPoint<T, D>, FixedPointError> {
+    if values.is_empty() {
+        return Err(FixedPointError::StatsInvalidInput);
+    }
+    let sorted = sorted(values);
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        return Ok(sorted[middle]);
+    }
+    let mut sum = WideSum::default();
+    for value in &sorted[middle - 1..=middle] {
+        sum.add(value.sign(), value.raw().unsigned_abs().into())?;
+    }
+    sum.div(2.into(), mode)
+}
+
+/// Computes the `p`th percentile of `values` for `p` in `[0, 1]`, rounding
+/// according to `mode`. Returns an error if `values` is empty or `p` isn't in
+/// `[0, 1]`.
+///
+/// Like NumPy's default method, the percentile linearly interpolates between
+/// the two values whose ranks surround `p * (n - 1)` in the sorted values.
+pub fn percentile<T: FixedPointValue, const D: u8>(
+    values: &[FixedPoint<T, D>],
+    p: FixedPoint<T, D>,
+    mode: RoundingMode,
+) -> Result<FixedPoint<T, D>, FixedPointError> {
+    let one = FixedPoint::<T, D>::ONE.raw().unsigned_abs();
+    if values.is_empty() || p.is_negative() || p.raw().unsigned_abs() > one {
+        return Err(FixedPointError::StatsInvalidInput);
+    }
+    let sorted = sorted(values);
+
+    // Split the rank into an index and a fraction at the scale of `ONE`.
+    let rank = p.raw().unsigned_abs().full_mul((sorted.len() - 1).into());
+    let (index, fraction) = rank.div_mod(one.into());
+    let index = index.as_usize();
+    if fraction.is_zero() {
+        return Ok(sorted[index]);
+    }
+
+    // Interpolate as `(lower * (ONE - fraction) + upper * fraction) / ONE`.
+    let fraction = U256::try_from(fraction).unwrap();
+    let (lower, upper) = (sorted[index], sorted[index + 1]);
+    let mut sum = WideSum::default();
+    sum.add(
+        lower.sign(),
+        lower.raw().unsigned_abs().full_mul(one - fraction),
+    )?;
+    sum.add(upper.sign(), upper.raw().unsigned_abs().full_mul(fraction))?;
+    sum.div(one.into(), mode)
+}
+
+/// Computes the exponential moving average of `values` with the smoothing
+/// factor `alpha` in `[0, 1]`, rounding each step according to `mode`.
+/// Returns an error if `values` is empty or `alpha` isn't in `[0, 1]`.
+///
+/// The average starts at the first value and each following value updates it
+/// to `alpha * value + (1 - alpha) * average`. Each step only rounds once,
+/// and since a larger average leads to a larger next average, `Floor` and
+/// `Ceil` bound the exact result from below and above. `Down` and `Up` round
+/// toward and away from zero, so they only do the same when the values are
+/// all nonnegative.
+pub fn ema<T: FixedPointValue, const D: u8>(
+    values: &[FixedPoint<T, D>],
+    alpha: FixedPoint<T, D>,
+    mode: RoundingMode,
+) -> Result<FixedPoint<T, D>, FixedPointError> {
+    let one = FixedPoint::<T, D>::ONE.raw().unsigned_abs();
+    if alpha.is_negative() || alpha.raw().unsigned_abs() > one {
+        return Err(FixedPointError::StatsInvalidInput);
+    }
+    let (&first, rest) = values
+        .split_first()
+        .ok_or(FixedPointError::StatsInvalidInput)?;
+    let alpha = alpha.raw().unsigned_abs();
+
+    rest.iter().try_fold(first, |average, value| {
+        let mut sum = WideSum::default();
+        sum.add(value.sign(), value.raw().unsigned_abs().full_mul(alpha))?;
+        sum.add(
+            average.sign(),
+            average.raw().unsigned_abs().full_mul(one - alpha),
+        )?;
+        sum.div(one.into(), mode)
+    })
+}
+
+/// Returns a sorted copy of `values`.
+fn sorted<T: FixedPointValue, const D: u8>(values: &[FixedPoint<T, D>]) -> Vec<FixedPoint<T, D>> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    sorted
+}
+
+/// A signed sum of `U512` magnitudes, which keeps the positive and negative
+/// parts separately so that only their totals can overflow.
+#[derive(Default)]
+pub(crate) struct WideSum {
+    positive: U512,
+    negative: U512,
+}
+
+impl WideSum {
+    /// Adds a magnitude with the given `sign` to the sum, returning an error
+    /// if the positive or negative part overflows a `U512`.
+    pub(crate) fn add(&mut self, sign: FixedPointSign, abs: U512) -> Result<(), FixedPointError> {
+        let part = if sign.is_negative() {
+            &mut self.negative
+        } else {
+            &mut self.positive
+        };
+        *part = part.checked_add(abs).ok_or(FixedPointError::Overflow)?;
+        Ok(())
+    }
+
+    /// Returns the sign and magnitude of the sum.
+    fn into_sign_and_abs(self) -> (FixedPointSign, U512) {
+        if self.positive >= self.negative {
+            (FixedPointSign::Positive, self.positive - self.negative)
+        } else {
+            (FixedPointSign::Negative, self.negative - self.positive)
+        }
+    }
+
+    /// Divides the sum by `divisor`, rounding according to `mode` and
+    /// returning an error if the result overflows `T`.
+    pub(crate) fn div<T: FixedPointValue, const D: u8>(
+        self,
+        divisor: U512,
+        mode: RoundingMode,
+    ) -> Result<FixedPoint<T, D>, FixedPointError> {
+        let (sign, abs) = self.into_sign_and_abs();
+        let (abs, rem) = abs.div_mod(divisor);
+        let mut abs = U256::try_from(abs).map_err(|_| FixedPointError::Overflow)?;
+        if mode.rounds_away_from_zero(sign, abs.bit(0), rem, divisor) {
+            abs = abs
+                .checked_add(U256::one())
+                .ok_or(FixedPointError::Overflow)?;
+        }
+        FixedPoint::from_sign_and_abs(sign, abs).map_err(|_| FixedPointError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::I256;
+    use num_bigint::BigInt;
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::{fixed, fixed_i128, fixed_i256, fixed_u128, fixed_u256};
+
+    #[test]
+    fn test_mean() {
+        use RoundingMode::*;
+
+        let values = [fixed_u256!(1e18), fixed!(2e18), fixed!(4e18)];
+        assert_eq!(mean(&values, Down), Ok(fixed!(2.333333333333333333e18)));
+        assert_eq!(mean(&values, Up), Ok(fixed!(2.333333333333333334e18)));
+        let values = [fixed_i256!(-1e18), fixed!(-2e18)];
+        assert_eq!(mean(&values, Floor), Ok(fixed!(-1.5e18)));
+        let values = [fixed_i128!(-1), fixed!(-2)];
+        assert_eq!(mean(&values, Floor), Ok(fixed!(-2)));
+        assert_eq!(mean(&values, Ceil), Ok(fixed!(-1)));
+
+        // The sum would overflow `T`.
+        let max = FixedPoint::<U256>::MAX;
+        assert_eq!(mean(&[max; 3], Down), Ok(max));
+
+        let values = [
+            (fixed_u128!(10e18), fixed!(1e18)),
+            (fixed!(20e18), fixed!(3e18)),
+        ];
+        assert_eq!(weighted_mean(&values, Down), Ok(fixed!(17.5e18)));
+        let values = [
+            (fixed_i256!(-1e18), fixed!(2e18)),
+            (fixed!(1e18), fixed!(0)),
+        ];
+        assert_eq!(weighted_mean(&values, Down), Ok(fixed!(-1e18)));
+
+        // Invalid inputs.
+        assert_eq!(
+            mean::<U256, 18>(&[], Down),
+            Err(FixedPointError::StatsInvalidInput)
+        );
+        assert_eq!(
+            weighted_mean(&[(fixed_i256!(1e18), fixed!(-1e18))], Down),
+            Err(FixedPointError::StatsInvalidInput)
+        );
+        assert_eq!(
+            weighted_mean(&[(fixed_u256!(1e18), fixed!(0))], Down),
+            Err(FixedPointError::StatsInvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_variance() {
+        use RoundingMode::*;
+
+        let values = [fixed_u256!(1e18), fixed!(2e18), fixed!(3e18), fixed!(4e18)];
+        assert_eq!(variance(&values, Down), Ok(fixed!(1.25e18)));
+        let values = [
+            fixed_i128!(2e18),
+            fixed!(4e18),
+            fixed!(4e18),
+            fixed!(4e18),
+            fixed!(5e18),
+            fixed!(5e18),
+            fixed!(7e18),
+            fixed!(9e18),
+        ];
+        assert_eq!(variance(&values, Down), Ok(fixed!(4e18)));
+        assert_eq!(std_dev(&values, Down), Ok(fixed!(2e18)));
+        let values = [fixed_i256!(-1e18), fixed!(1e18)];
+        assert_eq!(variance(&values, Up), Ok(fixed!(1e18)));
+
+        // The exact variance of 0, 0 and 1 wei is `2 / 9` wei squared, which
+        // is much less than one wei at the scale of `ONE`.
+        let values = [fixed_u256!(0), fixed!(0), fixed!(1)];
+        assert_eq!(variance(&values, Down), Ok(fixed!(0)));
+        assert_eq!(variance(&values, Up), Ok(fixed!(1)));
+        assert_eq!(variance(&[fixed_u256!(1); 3], Up), Ok(fixed!(0)));
+
+        // The deviations would overflow `T`.
+        let values = [FixedPoint::<I256, 0>::MIN, FixedPoint::MAX];
+        assert_eq!(variance(&values, Down), Err(FixedPointError::Overflow));
+
+        assert_eq!(
+            std_dev::<U256, 18>(&[], Down),
+            Err(FixedPointError::StatsInvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_percentile() {
+        use RoundingMode::*;
+
+        assert_eq!(
+            median(&[fixed_u256!(3e18), fixed!(1e18), fixed!(2e18)], Down),
+            Ok(fixed!(2e18))
+        );
+        let values = [fixed_i256!(4e18), fixed!(1e18), fixed!(-3e18), fixed!(2e18)];
+        assert_eq!(median(&values, Down), Ok(fixed!(1.5e18)));
+        assert_eq!(median(&[fixed_u256!(1), fixed!(2)], Down), Ok(fixed!(1)));
+        assert_eq!(median(&[fixed_u256!(1), fixed!(2)], Up), Ok(fixed!(2)));
+
+        let values = [
+            fixed_u128!(5e18),
+            fixed!(3e18),
+            fixed!(1e18),
+            fixed!(4e18),
+            fixed!(2e18),
+        ];
+        assert_eq!(percentile(&values, fixed!(0), Down), Ok(fixed!(1e18)));
+        assert_eq!(percentile(&values, fixed!(0.25e18), Down), Ok(fixed!(2e18)));
+        assert_eq!(
+            percentile(&values, fixed!(0.1e18), Down),
+            Ok(fixed!(1.4e18))
+        );
+        assert_eq!(percentile(&values, fixed!(0.5e18), Down), Ok(fixed!(3e18)));
+        assert_eq!(percentile(&values, fixed!(1e18), Down), Ok(fixed!(5e18)));
+
+        // Invalid inputs.
+        assert_eq!(
+            median::<U256, 18>(&[], Down),
+            Err(FixedPointError::StatsInvalidInput)
+        );
+        assert_eq!(
+            percentile(&values, fixed!(1.1e18), Down),
+            Err(FixedPointError::StatsInvalidInput)
+        );
+        assert_eq!(
+            percentile(&[fixed_i256!(1e18)], fixed!(-0.1e18), Down),
+            Err(FixedPointError::StatsInvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_ema() {
+        use RoundingMode::*;
+
+        let values = [fixed_u256!(1e18), fixed!(2e18), fixed!(3e18)];
+        assert_eq!(ema(&values, fixed!(0.5e18), Down), Ok(fixed!(2.25e18)));
+        assert_eq!(ema(&values, fixed!(1e18), Down), Ok(fixed!(3e18)));
+        assert_eq!(ema(&values, fixed!(0), Down), Ok(fixed!(1e18)));
+        let values = [fixed_i256!(0), fixed!(-1e18)];
+        assert_eq!(
+            ema(&values, fixed!(0.333333333333333333e18), Floor),
+            Ok(fixed!(-0.333333333333333333e18))
+        );
+        let values = [fixed_i256!(0), fixed!(1)];
+        assert_eq!(ema(&values, fixed!(0.5e18), Down), Ok(fixed!(0)));
+        assert_eq!(ema(&values, fixed!(0.5e18), Up), Ok(fixed!(1)));
+
+        // With negative values, only `Floor` and `Ceil` bound the exact
+        // average of `-0.5` wei.
+        let values = [fixed_i256!(0), fixed!(-1)];
+        assert_eq!(ema(&values, fixed!(0.5e18), Floor), Ok(fixed!(-1)));
+        assert_eq!(ema(&values, fixed!(0.5e18), Ceil), Ok(fixed!(0)));
+        assert_eq!(ema(&values, fixed!(0.5e18), Down), Ok(fixed!(0)));
+
+        // Invalid inputs.
+        assert_eq!(
+            ema::<U256, 18>(&[], fixed!(0.5e18), Down),
+            Err(FixedPointError::StatsInvalidInput)
+        );
+        assert_eq!(
+            ema(&values, fixed!(2e18), Down),
+            Err(FixedPointError::StatsInvalidInput)
+        );
+    }
+
+    #[test]
+    fn fuzz_stats() {
+        use RoundingMode::*;
+
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            // Small values give variances below one, where the rounding of
+            // the variance dominates the error of the standard deviation.
+            let bound = if rng.gen_bool(0.5) {
+                fixed_i256!(1e30)
+            } else {
+                fixed!(1e9)
+            };
+            let values = (0..rng.gen_range(1..=20))
+                .map(|_| rng.gen_range(-bound..=bound))
+                .collect::<Vec<_>>();
+
+            // The mean matches the sum divided by the count.
+            let sum = values.iter().fold(fixed!(0), |sum, &value| sum + value);
+            let count = FixedPoint::<I256>::from(I256::from(values.len()));
+            assert_eq!(
+                mean(&values, Down),
+                Ok(FixedPoint::new(sum.raw() / count.raw())),
+                "{values:?}"
+            );
+
+            // The variance doesn't change when the values are shifted.
+            let shift = rng.gen_range(fixed_i256!(-1e30)..=fixed!(1e30));
+            let shifted = values
+                .iter()
+                .map(|&value| value + shift)
+                .collect::<Vec<_>>();
+            let down = variance(&values, Down).unwrap();
+            let up = variance(&values, Up).unwrap();
+            assert_eq!(variance(&shifted, Down), Ok(down), "{values:?}");
+            assert_eq!(variance(&shifted, Up), Ok(up), "{values:?}");
+            assert!(up - down <= fixed!(1), "{values:?}");
+
+            // The variance is `(n * Σ x^2 - (Σ x)^2) / (n^2 * ONE)` rounded
+            // down or up.
+            let one = BigInt::from(10_u64.pow(18));
+            let n = BigInt::from(values.len());
+            let raws = values
+                .iter()
+                .map(|value| value.raw().to_string().parse::<BigInt>().unwrap())
+                .collect::<Vec<_>>();
+            let sum = raws.iter().sum::<BigInt>();
+            let squares = raws.iter().map(|raw| raw * raw).sum::<BigInt>();
+            let numerator = &n * squares - &sum * &sum;
+            let denominator = &n * &n * &one;
+            let exact_down = &numerator / &denominator;
+            let exact_up = (&numerator + &denominator - 1_u8) / &denominator;
+            assert_eq!(to_big_int(down), exact_down, "{values:?}");
+            assert_eq!(to_big_int(up), exact_up, "{values:?}");
+
+            // The standard deviation is the root of the rounded variance, so
+            // it bounds the exact root `sqrt(numerator * ONE / denominator)`
+            // and is within one unit of the correctly rounded root when the
+            // variance is at least one.
+            let root_down = BigInt::sqrt(&(&exact_down * &one));
+            let mut root_up = BigInt::sqrt(&(&exact_up * &one));
+            if &root_up * &root_up < &exact_up * &one {
+                root_up += 1_u8;
+            }
+            let down = to_big_int(std_dev(&values, Down).unwrap());
+            let up = to_big_int(std_dev(&values, Up).unwrap());
+            assert_eq!(down, root_down, "{values:?}");
+            assert_eq!(up, root_up, "{values:?}");
+            let exact = &numerator * &one;
+            assert!(&down * &down * &denominator <= exact, "{values:?}");
+            assert!(&up * &up * &denominator >= exact, "{values:?}");
+            if exact_down >= one {
+                let next = &down + 2_u8;
+                assert!(&next * &next * &denominator > exact, "{values:?}");
+                let previous = &up - 2_u8;
+                assert!(&previous * &previous * &denominator < exact, "{values:?}");
+            }
+
+            // The median is the 50th percentile.
+            assert_eq!(
+                median(&values, HalfEven),
+                percentile(&values, fixed!(0.5e18), HalfEven),
+                "{values:?}"
+            );
+        }
+    }
+
+    fn to_big_int(value: FixedPoint<I256>) -> BigInt {
+        value.raw().to_string().parse().unwrap()
+    }
+}
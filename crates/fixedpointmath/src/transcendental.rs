@@ -0,0 +1,902 @@
+use ethers::types::{I256, U256, U512};
+
+use crate::{
+    exp, int256, ln, FixedPoint, FixedPointError, FixedPointSign, FixedPointValue, RoundingMode,
+    DEFAULT_DECIMALS,
+};
+
+/// The maximum relative error of `exp` with 18 decimals, in units of `1e-18`.
+///
+/// `exp` converts its input to a `2^96` basis and subtracts `k * ln(2)` for
+/// an integer `|k| <= 195`. Both are off by less than `2^-96` per unit, which
+/// adds a relative error below `2e-27` to the result. Its rational
+/// approximation of `e^r` for `|r| <= ln(2) / 2` has a relative error below
+/// `2e-20`, which is largest at the edges of that range. The result is then
+/// truncated to 18 decimals, which is off by less than one unit in the last
+/// place and accounted for separately. The bound rounds the `2e-20` up to
+/// `1e-17`, so it holds with a wide margin.
+const MAX_EXP_RELATIVE_ERROR: i128 = 10;
+
+/// The maximum absolute error of `ln` with 18 decimals, in units of `1e-18`.
+///
+/// `ln` normalizes its input to `[1, 2)` with an exact shift, so its error
+/// comes from its rational approximation of `ln(x)` on that range, which is
+/// off by less than `6e-20`, and the final truncation toward negative
+/// infinity, which is off by less than `1e-18`. The total is below `1.1e-18`,
+/// which is rounded up to whole units.
+const MAX_LN_ERROR: i128 = 2;
+
+/// The maximum relative error of `exp_36` before it rounds its result, in
+/// units of `1e-36`.
+///
+/// `exp_36` subtracts `k * ln(2)` from its input for an integer `|k| <= 260`,
+/// using `ln(2)` rounded to 36 decimals, which shifts `r` by less than
+/// `1.3e-34`. Each term of its Taylor series for `e^r` is truncated twice and
+/// it has fewer than 40 terms, so the sum is off by less than `1.2e-34` for a
+/// result of at least `0.7`. The total is below `3e-34` (`6.1e-35` was the
+/// largest error observed against a 60 decimal reference), which is rounded up
+/// to `1e-33`.
+const MAX_EXP_36_RELATIVE_ERROR: i128 = 1_000;
+
+/// The maximum absolute error of `ln_36`, in units of `1e-36`.
+///
+/// `ln_36` normalizes its input to `[0.75, 1.5)` times `2^k` for `|k| <= 256`
+/// with an exact shift, so adding `k * ln(2)` with `ln(2)` rounded to 36
+/// decimals is off by less than `1.3e-34`. Its series for the logarithm of
+/// the mantissa truncates each of its fewer than 30 terms, which is off by
+/// less than `7e-35`. The total is below `2e-34` (`4.6e-35` was the largest
+/// error observed against a 60 decimal reference), which is rounded up to
+/// `1e-33`.
+const MAX_LN_36_ERROR: i128 = 1_000;
+
+/// One with 36 decimals, the scale used by `ln_precise` and `pow_precise`.
+const ONE_36: i128 = 1_000_000_000_000_000_000_000_000_000_000_000_000;
+
+/// The largest magnitude of `y * ln(x)` with 36 decimals that `pow_precise`
+/// passes to `exp`. Every `T` has fewer than 256 bits, so a larger result
+/// overflows and a smaller one rounds to zero at any scale.
+const MAX_EXP_36: u128 = 180 * ONE_36 as u128;
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Computes `e^self`, returning an error if the result overflows `T`.
+    ///
+    /// The `exp` approximation operates on values with 18 decimals, so with
+    /// up to 18 decimals, self is converted to 18 decimals and the result is
+    /// converted back to the scale of self, rounding toward zero. With more
+    /// decimals, the result is computed with 36 significant decimals and
+    /// rounded toward zero, so it's exact to the last place for up to about
+    /// 33 decimals. Inputs with more than 36 decimals are rounded toward zero
+    /// to 36 decimals first.
+    pub fn exp(self) -> Result<Self, FixedPointError> {
+        if D > DEFAULT_DECIMALS {
+            return Self::exp_36(self.to_exp_36_input(RoundingMode::Down), RoundingMode::Down);
+        }
+        Self::from_wad(exp(self.to_wad(RoundingMode::Down)?)?, RoundingMode::Down)
+    }
+
+    /// Computes the natural logarithm of self, returning an error if self
+    /// isn't positive or the result can't be represented by `T`, e.g., if the
+    /// result is negative and `T` is unsigned.
+    ///
+    /// The `ln` approximation operates on values with 18 decimals, so with up
+    /// to 18 decimals, self is converted to 18 decimals and the result is
+    /// converted back to the scale of self, rounding toward zero. With more
+    /// decimals, the logarithm of self is computed with 36 decimals and
+    /// rounded toward zero, so it's exact to the last place for up to about
+    /// 33 decimals.
+    pub fn ln(self) -> Result<Self, FixedPointError> {
+        if D > DEFAULT_DECIMALS {
+            return Self::from_36_rounding(self.ln_36()?, RoundingMode::Down);
+        }
+        Self::from_wad(ln(self.to_wad(RoundingMode::Down)?)?, RoundingMode::Down)
+    }
+
+    /// Computes `e^self`, rounded up so that the result is at or above the
+    /// true value.
+    ///
+    /// `exp` is within a relative error of `2e-20` of the true value before
+    /// it truncates its result, so adding `1e-17` times the result plus one
+    /// unit in the last place always reaches the true value. The result is at
+    /// most that far above it. With more than 18 decimals, the result is
+    /// computed with 36 significant decimals and a relative error below
+    /// `3e-34` instead, and `1e-33` times the result is added. `e^0` is
+    /// exactly one.
+    pub fn exp_up(self) -> Result<Self, FixedPointError> {
+        if self.is_zero() {
+            return Ok(Self::ONE);
+        }
+        if D > DEFAULT_DECIMALS {
+            let (mantissa, k) = exp_36_parts(self.to_exp_36_input(RoundingMode::Ceil));
+            return Self::scale_exp_36(mantissa + exp_36_error(mantissa), k, RoundingMode::Ceil);
+        }
+        let result = exp(self.to_wad(RoundingMode::Ceil)?)?;
+        Self::from_wad(result + exp_error(result), RoundingMode::Ceil)
+    }
+
+    /// Computes `e^self`, rounded down so that the result is at or below the
+    /// true value.
+    ///
+    /// `exp` is within a relative error of `2e-20` of the true value before
+    /// it truncates its result, so subtracting `1e-17` times the result plus
+    /// one unit in the last place always reaches the true value. The result
+    /// is at most that far below it. With more than 18 decimals, the result
+    /// is computed with 36 significant decimals and a relative error below
+    /// `3e-34` instead, and `1e-33` times the result is subtracted. `e^0` is
+    /// exactly one.
+    pub fn exp_down(self) -> Result<Self, FixedPointError> {
+        if self.is_zero() {
+            return Ok(Self::ONE);
+        }
+        if D > DEFAULT_DECIMALS {
+            let (mantissa, k) = exp_36_parts(self.to_exp_36_input(RoundingMode::Floor));
+            return Self::scale_exp_36(mantissa - exp_36_error(mantissa), k, RoundingMode::Floor);
+        }
+        let result = exp(self.to_wad(RoundingMode::Floor)?)?;
+        Self::from_wad(
+            (result - exp_error(result)).max(I256::zero()),
+            RoundingMode::Floor,
+        )
+    }
+
+    /// Computes the natural logarithm of self, rounded up so that the result
+    /// is at or above the true value.
+    ///
+    /// `ln` is off by less than `1.1e-18`, so adding `2e-18` always reaches
+    /// the true value. The result is at most `3.1e-18` above it at 18
+    /// decimals. With more than 18 decimals, the logarithm is computed with
+    /// 36 decimals and an error below `2e-34` instead, and `1e-33` is added.
+    /// `ln(1)` is exactly zero.
+    pub fn ln_up(self) -> Result<Self, FixedPointError> {
+        if self == Self::ONE {
+            return Ok(Self::zero());
+        }
+        if D > DEFAULT_DECIMALS {
+            return Self::from_36_rounding(
+                self.ln_36()? + I256::from(MAX_LN_36_ERROR),
+                RoundingMode::Ceil,
+            );
+        }
+        let result = ln(self.to_wad(RoundingMode::Ceil)?)?;
+        Self::from_wad(result + I256::from(MAX_LN_ERROR), RoundingMode::Ceil)
+    }
+
+    /// Computes the natural logarithm of self, rounded down so that the
+    /// result is at or below the true value.
+    ///
+    /// `ln` is off by less than `1.1e-18`, so subtracting `2e-18` always
+    /// reaches the true value. The result is at most `3.1e-18` below it at 18
+    /// decimals. With more than 18 decimals, the logarithm is computed with
+    /// 36 decimals and an error below `2e-34` instead, and `1e-33` is
+    /// subtracted. `ln(1)` is exactly zero.
+    pub fn ln_down(self) -> Result<Self, FixedPointError> {
+        if self == Self::ONE {
+            return Ok(Self::zero());
+        }
+        if D > DEFAULT_DECIMALS {
+            return Self::from_36_rounding(
+                self.ln_36()? - I256::from(MAX_LN_36_ERROR),
+                RoundingMode::Floor,
+            );
+        }
+        let result = ln(self.to_wad(RoundingMode::Floor)?)?;
+        Self::from_wad(result - I256::from(MAX_LN_ERROR), RoundingMode::Floor)
+    }
+
+    /// Computes self raised to the power of `y`, rounded up so that the
+    /// result is at or above the true value.
+    ///
+    /// Like Balancer's `powUp`, this adds the maximum error of `pow` to its
+    /// result. The maximum relative error is `(2 * |y| + 11) * 1e-18`: the
+    /// error of `ln` scaled by `y`, the truncation of `y * ln(x)`, and the
+    /// relative error of `exp`, plus one unit in the last place for the
+    /// truncation of `exp`. Negative powers are computed as `1 / x^-y`, which
+    /// adds a relative error of `x^y * 1e-18`. Powers that are known exactly,
+    /// i.e., when `y` is zero or one or self is zero or one, aren't adjusted.
+    pub fn pow_up(self, y: Self) -> Result<Self, FixedPointError> {
+        let (x, y_wad) = self.pow_inputs(y, RoundingMode::Ceil)?;
+        if let Some(result) = self.exact_pow(y) {
+            return Ok(result);
+        }
+        let result = FixedPoint::<I256>::new(x).checked_pow(FixedPoint::new(y_wad))?;
+        Self::from_wad(
+            result.raw() + pow_error(result.raw(), y_wad),
+            RoundingMode::Ceil,
+        )
+    }
+
+    /// Computes self raised to the power of `y`, rounded down so that the
+    /// result is at or below the true value.
+    ///
+    /// Like Balancer's `powDown`, this subtracts the maximum error of `pow`
+    /// from its result. The maximum relative error is `(2 * |y| + 11) * 1e-18`:
+    /// the error of `ln` scaled by `y`, the truncation of `y * ln(x)`, and the
+    /// relative error of `exp`, plus one unit in the last place for the
+    /// truncation of `exp`. Negative powers are computed as `1 / x^-y`, which
+    /// adds a relative error of `x^y * 1e-18`. Powers that are known exactly,
+    /// i.e., when `y` is zero or one or self is zero or one, aren't adjusted.
+    pub fn pow_down(self, y: Self) -> Result<Self, FixedPointError> {
+        let (x, y_wad) = self.pow_inputs(y, RoundingMode::Floor)?;
+        if let Some(result) = self.exact_pow(y) {
+            return Ok(result);
+        }
+        let result = FixedPoint::<I256>::new(x).checked_pow(FixedPoint::new(y_wad))?;
+        Self::from_wad(
+            (result.raw() - pow_error(result.raw(), y_wad)).max(I256::zero()),
+            RoundingMode::Floor,
+        )
+    }
+
+    /// Computes the natural logarithm of self, rounded to the nearest value.
+    ///
+    /// Unlike `ln`, which is off by up to `2e-18`, the logarithm is computed
+    /// with 36 decimals and rounded once at the end, so the result is correct
+    /// to within one unit in the last place.
+    pub fn ln_precise(self) -> Result<Self, FixedPointError> {
+        Self::from_36_rounding(self.ln_36()?, RoundingMode::HalfUp)
+    }
+
+    /// Computes self raised to the power of `y`, rounded to the nearest value.
+    ///
+    /// Unlike `pow`, whose error grows with `y`, `y * ln(self)` and its
+    /// exponential are computed with 36 decimals and the result is rounded
+    /// once at the end. This keeps the result within one unit in the last
+    /// place for large exponents of bases close to one, e.g., compounding a
+    /// per-second rate over a year.
+    pub fn pow_precise(self, y: Self) -> Result<Self, FixedPointError> {
+        self.pow_36(y, RoundingMode::HalfUp)
+    }
+
+    /// Computes self raised to the power of `y` by computing `y * ln(self)`
+    /// and its exponential with 36 decimals, rounding the result according to
+    /// `mode`.
+    pub(crate) fn pow_36(self, y: Self, mode: RoundingMode) -> Result<Self, FixedPointError> {
+        if y.is_zero() {
+            return Ok(Self::ONE);
+        }
+        if self.is_zero() {
+            if y.is_negative() {
+                return Err(FixedPointError::DivisionByZero);
+            }
+            return Ok(Self::zero());
+        }
+
+        // Compute `y * ln(self)` with 36 decimals.
+        let ln = self.ln_36()?;
+        let sign = y.sign().flip_if(ln.is_negative());
+        let abs = U512::from(y.raw().unsigned_abs()) * U512::from(ln.unsigned_abs())
+            / U512::from(10).pow(D.into());
+
+        if abs > U512::from(MAX_EXP_36) {
+            if sign.is_negative() {
+                return Ok(Self::zero());
+            }
+            return Err(FixedPointError::PowOverflow {
+                base: self.to_string(),
+                exponent: y.to_string(),
+            });
+        }
+        let ylnx = I256::from_raw(U256::try_from(abs).unwrap());
+        let ylnx = if sign.is_negative() { -ylnx } else { ylnx };
+        Self::exp_36(ylnx, mode).map_err(|_| FixedPointError::PowOverflow {
+            base: self.to_string(),
+            exponent: y.to_string(),
+        })
+    }
+
+    /// Computes the natural logarithm of self with 36 decimals.
+    pub(crate) fn ln_36(self) -> Result<I256, FixedPointError> {
+        if self.is_negative() || self.is_zero() {
+            return Err(FixedPointError::LnInvalidInput);
+        }
+
+        // Find `k` such that `1 <= numerator / denominator < 2`, where
+        // `numerator / denominator = self / 2^k`.
+        let mut numerator = U512::from(self.raw().unsigned_abs());
+        let mut denominator = U512::from(Self::ONE.raw().unsigned_abs());
+        let mut k = 0_i64;
+        while numerator >= denominator << 1 {
+            denominator <<= 1;
+            k += 1;
+        }
+        while numerator < denominator {
+            numerator <<= 1;
+            k -= 1;
+        }
+
+        // Move the mantissa to `[0.75, 1.5)` so that `z` below is small.
+        let one = I256::from(ONE_36);
+        let mut mantissa = numerator * U512::from(ONE_36) / denominator;
+        if mantissa >= U512::from(ONE_36 / 2 * 3) {
+            mantissa = numerator * U512::from(ONE_36) / (denominator << 1);
+            k += 1;
+        }
+        let mantissa = I256::from(mantissa.as_u128());
+
+        // `ln(m) = 2 * atanh(z) = 2 * (z + z^3 / 3 + z^5 / 5 + ...)` where
+        // `z = (m - 1) / (m + 1)`. Since `|z| <= 0.2`, each term is at least
+        // 25 times smaller than the last.
+        let z = (mantissa - one) * one / (mantissa + one);
+        let z_squared = z * z / one;
+        let mut term = z;
+        let mut sum = z;
+        let mut n = 1;
+        loop {
+            term = term * z_squared / one;
+            if term.is_zero() {
+                break;
+            }
+            n += 2;
+            sum += term / I256::from(n);
+        }
+
+        Ok(I256::from(k) * FixedPoint::<I256, 36>::LN_2.raw() + sum * 2)
+    }
+
+    /// Computes `e^x` for `x` with 36 decimals, rounding the result according
+    /// to `mode` at the scale of self.
+    pub(crate) fn exp_36(x: I256, mode: RoundingMode) -> Result<Self, FixedPointError> {
+        let (mantissa, k) = exp_36_parts(x);
+        Self::scale_exp_36(mantissa, k, mode)
+    }
+
+    /// Converts `mantissa * 2^k` for a `mantissa` with 36 decimals to the
+    /// scale and type of self, rounding according to `mode`.
+    fn scale_exp_36(mantissa: I256, k: i32, mode: RoundingMode) -> Result<Self, FixedPointError> {
+        let mut numerator =
+            U512::from(mantissa.into_raw()) * U512::from(Self::ONE.raw().unsigned_abs());
+        let mut denominator = U512::from(ONE_36);
+        if k >= 0 {
+            if numerator.bits() + k as usize > 512 {
+                return Err(FixedPointError::Overflow);
+            }
+            numerator <<= k;
+        } else {
+            denominator <<= -k;
+        }
+        let (abs, rem) = numerator.div_mod(denominator);
+        let abs =
+            if mode.rounds_away_from_zero(FixedPointSign::Positive, abs.bit(0), rem, denominator) {
+                abs + 1
+            } else {
+                abs
+            };
+        let abs = U256::try_from(abs).map_err(|_| FixedPointError::Overflow)?;
+        Self::from_sign_and_abs(FixedPointSign::Positive, abs)
+            .map_err(|_| FixedPointError::Overflow)
+    }
+
+    /// Returns self raised to the power of `y` if it's known exactly, i.e., if
+    /// `y` is zero or one or self is zero or one.
+    fn exact_pow(self, y: Self) -> Option<Self> {
+        if y.is_zero() || self == Self::ONE {
+            Some(Self::ONE)
+        } else if y == Self::ONE {
+            Some(self)
+        } else if self.is_zero() && y.is_positive() {
+            Some(Self::zero())
+        } else {
+            None
+        }
+    }
+
+    /// Converts self and `y` to 18 decimals for `pow`, rounding each in the
+    /// direction that moves `self^y` in the direction of `mode`.
+    fn pow_inputs(self, y: Self, mode: RoundingMode) -> Result<(I256, I256), FixedPointError> {
+        // The error bounds assume that `pow` is computed with `ln(self)`, so
+        // negative bases aren't supported.
+        if self.is_negative() {
+            return Err(FixedPointError::LnInvalidInput);
+        }
+
+        // `x^y` increases with `x` when `y` is positive and with `y` when `x`
+        // is greater than one.
+        let x_mode = if y.is_negative() {
+            mode.reverse()
+        } else {
+            mode
+        };
+        let y_mode = if self < Self::ONE {
+            mode.reverse()
+        } else {
+            mode
+        };
+        Ok((self.to_wad(x_mode)?, y.to_wad(y_mode)?))
+    }
+
+    /// Converts self to a value with 18 decimals, which is the scale used by
+    /// `ln` and `exp`, rounding according to `mode`.
+    pub(crate) fn to_wad(self, mode: RoundingMode) -> Result<I256, FixedPointError> {
+        let value =
+            FixedPoint::<I256, D>::from_sign_and_abs(self.sign(), self.raw().unsigned_abs())
+                .map_err(|_| FixedPointError::UnsafeCastToInt256)?;
+        Ok(value.change_decimals::<DEFAULT_DECIMALS>(mode)?.raw())
+    }
+
+    /// Converts a value with 18 decimals to the scale and type of self,
+    /// rounding according to `mode`.
+    pub(crate) fn from_wad(wad: I256, mode: RoundingMode) -> Result<Self, FixedPointError> {
+        let value = FixedPoint::<I256>::new(wad).change_decimals::<D>(mode)?;
+        Self::from_sign_and_abs(value.sign(), value.raw().unsigned_abs())
+    }
+
+    /// Converts self to a value with 36 decimals for `exp_36`, rounding
+    /// according to `mode` and clamping its magnitude to `MAX_EXP_36`. The
+    /// exponential of a larger value overflows every `T`, and that of a
+    /// smaller one rounds to the same value as `e^-MAX_EXP_36` at any scale.
+    fn to_exp_36_input(self, mode: RoundingMode) -> I256 {
+        let one = U512::from(Self::ONE.raw().unsigned_abs());
+        let (abs, rem) = (U512::from(self.raw().unsigned_abs()) * U512::from(ONE_36)).div_mod(one);
+        let abs = if mode.rounds_away_from_zero(self.sign(), abs.bit(0), rem, one) {
+            abs + 1
+        } else {
+            abs
+        };
+        let abs = I256::from_raw(U256::try_from(abs.min(U512::from(MAX_EXP_36))).unwrap());
+        if self.is_negative() {
+            -abs
+        } else {
+            abs
+        }
+    }
+
+    /// Converts a value with 36 decimals to the scale and type of self,
+    /// rounding according to `mode`.
+    fn from_36_rounding(value: I256, mode: RoundingMode) -> Result<Self, FixedPointError> {
+        let value = FixedPoint::<I256, 36>::new(value).change_decimals::<D>(mode)?;
+        Self::from_sign_and_abs(value.sign(), value.raw().unsigned_abs())
+    }
+}
+
+/// Splits `e^x` for `x` with 36 decimals into a mantissa `e^r` with 36
+/// decimals and an integer `k` such that `e^x = e^r * 2^k`.
+fn exp_36_parts(x: I256) -> (I256, i32) {
+    // Factor out powers of two, where `|r| <= ln(2) / 2`.
+    let one = I256::from(ONE_36);
+    let ln_2 = FixedPoint::<I256, 36>::LN_2.raw();
+    let k = (x + ln_2 / 2).div_euclid(ln_2);
+    let r = x - k * ln_2;
+
+    // Evaluate the Taylor series of `e^r`.
+    let mut result = one;
+    let mut term = one;
+    let mut n = 1;
+    loop {
+        term = term * r / one / I256::from(n);
+        if term.is_zero() {
+            break;
+        }
+        result += term;
+        n += 1;
+    }
+    (result, k.as_i32())
+}
+
+/// Computes the maximum error of the result of `exp` with 18 decimals.
+fn exp_error(result: I256) -> I256 {
+    let error = FixedPoint::<I256>::new(result).mul_up(FixedPoint::new(MAX_EXP_RELATIVE_ERROR));
+    error.raw() + 1
+}
+
+/// Computes the maximum error of the mantissa of `exp_36` with 36 decimals.
+fn exp_36_error(mantissa: I256) -> I256 {
+    mantissa * I256::from(MAX_EXP_36_RELATIVE_ERROR) / I256::from(ONE_36) + 1
+}
+
+/// Computes the maximum error of the result of `pow` with 18 decimals given
+/// the exponent `y`.
+fn pow_error(result: I256, y: I256) -> I256 {
+    // The error of `ln(x)` is scaled by `y`, `y * ln(x)` is truncated, and
+    // `exp` adds its own error. The final division of negative powers and
+    // truncation to 18 decimals add at most one unit in the last place.
+    let mut relative_error = FixedPoint::<I256>::new(y.abs())
+        .mul_up(FixedPoint::new(MAX_LN_ERROR))
+        .raw()
+        + 1
+        + I256::from(MAX_EXP_RELATIVE_ERROR);
+
+    // Negative powers are computed as `1 / x^-y`, so the absolute error of
+    // `x^-y` becomes a relative error of `x^y / 1e18`.
+    if y.is_negative() {
+        relative_error += FixedPoint::<I256>::new(result)
+            .mul_up(FixedPoint::new(1))
+            .raw()
+            + 1;
+    }
+
+    // Since `e^δ - 1 <= δ + δ^2` for `δ <= 1`, the squared error covers the
+    // non-linearity of `exp` for large errors.
+    let relative_error = FixedPoint::<I256>::new(relative_error);
+    let relative_error = relative_error + relative_error.mul_up(relative_error);
+    FixedPoint::new(result).mul_up(relative_error).raw() + int256!(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::U256;
+    use num_bigint::{BigInt, BigUint};
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::{fixed, fixed_i256, fixed_u256, uint256};
+
+    /// The scale of the reference values, which is much finer than the
+    /// errors of the approximations.
+    fn scale() -> BigInt {
+        BigInt::from(10).pow(60)
+    }
+
+    fn to_bigint(value: I256) -> BigInt {
+        value.to_string().parse().unwrap()
+    }
+
+    /// Computes `e^x` for `x` with 60 decimals, returning a result with 60
+    /// decimals.
+    fn exp_reference(x: &BigInt) -> BigInt {
+        let scale = scale();
+
+        // Reduce the range of `x` by halving it so the Taylor series
+        // converges quickly, then square the result.
+        let halvings = 32;
+        let x = x >> halvings;
+        let mut result = scale.clone();
+        let mut term = scale.clone();
+        for i in 1.. {
+            term = term * &x / &scale / i;
+            if term == BigInt::ZERO {
+                break;
+            }
+            result += &term;
+        }
+        for _ in 0..halvings {
+            result = &result * &result / &scale;
+        }
+        result
+    }
+
+    /// Computes `ln(x)` for `x` with 60 decimals, returning a result with 60
+    /// decimals.
+    fn ln_reference(x: &BigInt) -> BigInt {
+        let scale = scale();
+
+        // Refine a float estimate with Halley's method, which triples the
+        // number of correct digits each iteration.
+        let estimate = (x.to_string().parse::<f64>().unwrap() / 1e60).ln();
+        let mut y = BigInt::from((estimate * 1e15) as i64) * BigInt::from(10).pow(45);
+        for _ in 0..4 {
+            let exp_y = exp_reference(&y);
+            y += (x - &exp_y) * 2 * &scale / (x + &exp_y);
+        }
+        y
+    }
+
+    /// Converts a value with 18 decimals to a reference value with 60
+    /// decimals.
+    fn from_wad(value: I256) -> BigInt {
+        to_bigint(value) * BigInt::from(10).pow(42)
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        assert_eq!(fixed_i256!(1e18).exp(), Ok(fixed!(2.718281828459045235e18)));
+        assert_eq!(
+            fixed_i256!(-1e18).exp(),
+            Ok(fixed!(0.367879441171442321e18))
+        );
+        assert_eq!(fixed_u256!(2e18).ln(), Ok(fixed!(0.693147180559945309e18)));
+        assert_eq!(
+            fixed_i256!(0.5e18).ln(),
+            Ok(fixed!(-0.693147180559945310e18))
+        );
+
+        // Other scales and types.
+        let x = FixedPoint::<U256, 6>::new(uint256!(1e6));
+        assert_eq!(x.exp().map(|x| x.raw()), Ok(uint256!(2.718281e6)));
+        let x = FixedPoint::<u128, 6>::new(2_000_000_u128);
+        assert_eq!(x.ln().map(|x| x.raw()), Ok(693_147));
+        assert_eq!(
+            x.pow(FixedPoint::new(10_000_000_u128)).map(|x| x.raw()),
+            Ok(1_023_999_999)
+        );
+        let x = FixedPoint::<U256, 0>::new(uint256!(20));
+        assert_eq!(x.ln().map(|x| x.raw()), Ok(uint256!(2)));
+        assert_eq!(x.exp().map(|x| x.raw()), Ok(uint256!(485165195)));
+
+        // More than 18 decimals keep every digit.
+        let x = FixedPoint::<i128, 27>::new(2_000_000_000_000_000_000_000_000_000_i128);
+        assert_eq!(
+            x.ln().map(|x| x.raw()),
+            Ok(693_147_180_559_945_309_417_232_121)
+        );
+        assert_eq!(
+            x.pow(FixedPoint::new(500_000_000_000_000_000_000_000_000_i128))
+                .map(|x| x.raw()),
+            Ok(1_414_213_562_373_095_048_801_688_724)
+        );
+        let x = FixedPoint::<i128, 27>::new(1_000_000_000_000_000_000_000_000_000_i128);
+        assert_eq!(
+            x.exp().map(|x| x.raw()),
+            Ok(2_718_281_828_459_045_235_360_287_471)
+        );
+        assert_eq!(
+            (-x).exp().map(|x| x.raw()),
+            Ok(367_879_441_171_442_321_595_523_770)
+        );
+        let x = FixedPoint::<i128, 24>::new(1_i128);
+        assert_eq!(
+            x.ln().map(|x| x.raw()),
+            Ok(-55_262_042_231_857_096_416_431_794)
+        );
+        assert_eq!(
+            FixedPoint::<U256, 76>::new(uint256!(1)).ln(),
+            Err(FixedPointError::Overflow)
+        );
+        assert_eq!(
+            FixedPoint::<i128, 24>::new(-1_000_000_000_000_000_000_000_000_i128).exp(),
+            Ok(FixedPoint::new(367_879_441_171_442_321_595_523_i128))
+        );
+        assert_eq!(
+            FixedPoint::<i128, 24>::new(i128::MIN).exp(),
+            Ok(FixedPoint::new(0_i128))
+        );
+        assert_eq!(
+            FixedPoint::<i128, 24>::new(i128::MAX).exp(),
+            Err(FixedPointError::Overflow)
+        );
+        assert_eq!(
+            FixedPoint::<i128, 24>::new(0_i128).ln(),
+            Err(FixedPointError::LnInvalidInput)
+        );
+
+        // Invalid inputs and overflow.
+        assert_eq!(fixed_u256!(0).ln(), Err(FixedPointError::LnInvalidInput));
+        assert!(matches!(
+            fixed_u256!(0.5e18).ln(),
+            Err(FixedPointError::ConversionOutOfRange { .. })
+        ));
+        assert_eq!(
+            fixed_i256!(136e18).exp(),
+            Err(FixedPointError::ExpInvalidExponent)
+        );
+        assert!(matches!(
+            FixedPoint::<i128, 6>::new(100_000_000_i128).exp(),
+            Err(FixedPointError::ConversionOutOfRange { to: "i128", .. })
+        ));
+    }
+
+    #[test]
+    fn test_exp_ln_bounds() {
+        assert_eq!(
+            fixed_i256!(1e18).exp_down(),
+            Ok(fixed!(2.718281828459045206e18))
+        );
+        assert_eq!(
+            fixed_i256!(1e18).exp_up(),
+            Ok(fixed!(2.718281828459045264e18))
+        );
+        assert_eq!(fixed_u256!(0).ln_up(), Err(FixedPointError::LnInvalidInput));
+        assert_eq!(
+            fixed_i256!(136e18).exp_up(),
+            Err(FixedPointError::ExpInvalidExponent)
+        );
+
+        // Exact results aren't adjusted.
+        assert_eq!(fixed_i256!(0).exp_down(), Ok(fixed!(1e18)));
+        assert_eq!(fixed_i256!(0).exp_up(), Ok(fixed!(1e18)));
+        assert_eq!(fixed_u256!(0).exp_down(), Ok(fixed!(1e18)));
+        assert_eq!(fixed_i256!(1e18).ln_down(), Ok(fixed!(0)));
+        assert_eq!(fixed_i256!(1e18).ln_up(), Ok(fixed!(0)));
+        assert_eq!(fixed_u256!(1e18).ln_down(), Ok(fixed!(0)));
+        assert_eq!(fixed_u256!(1e18).pow_down(fixed!(0)), Ok(fixed!(1e18)));
+        assert_eq!(fixed_u256!(3e18).pow_up(fixed!(0)), Ok(fixed!(1e18)));
+        assert_eq!(fixed_u256!(1e18).pow_up(fixed!(7.5e18)), Ok(fixed!(1e18)));
+        assert_eq!(fixed_u256!(3e18).pow_down(fixed!(1e18)), Ok(fixed!(3e18)));
+        assert_eq!(fixed_u256!(0).pow_up(fixed!(2e18)), Ok(fixed!(0)));
+
+        // Other results are within the bounds.
+        let x = fixed_u256!(4e18).pow_down(fixed!(0.5e18)).unwrap();
+        assert!(x < fixed!(2e18) && x > fixed!(1.99999999999999990e18));
+        let x = fixed_u256!(4e18).pow_up(fixed!(0.5e18)).unwrap();
+        assert!(x > fixed!(2e18) && x < fixed!(2.00000000000000010e18));
+        assert_eq!(
+            fixed_i256!(-2e18).pow_up(fixed!(3e18)),
+            Err(FixedPointError::LnInvalidInput)
+        );
+
+        // Other scales round the inputs and the results in the same
+        // direction.
+        let x = FixedPoint::<U256, 6>::new(uint256!(2e6));
+        assert_eq!(x.ln_down().map(|x| x.raw()), Ok(uint256!(0.693147e6)));
+        assert_eq!(x.ln_up().map(|x| x.raw()), Ok(uint256!(0.693148e6)));
+
+        // More than 18 decimals are bounded to the last place.
+        let x = FixedPoint::<i128, 24>::new(1_i128);
+        assert_eq!(
+            x.ln_down().map(|x| x.raw()),
+            Ok(-55_262_042_231_857_096_416_431_795)
+        );
+        assert_eq!(
+            x.ln_up().map(|x| x.raw()),
+            Ok(-55_262_042_231_857_096_416_431_794)
+        );
+        let x = FixedPoint::<i128, 27>::new(2_000_000_000_000_000_000_000_000_000_i128);
+        assert_eq!(
+            x.ln_down().map(|x| x.raw()),
+            Ok(693_147_180_559_945_309_417_232_121)
+        );
+        assert_eq!(
+            x.ln_up().map(|x| x.raw()),
+            Ok(693_147_180_559_945_309_417_232_122)
+        );
+        let x = FixedPoint::<i128, 27>::new(1_000_000_000_000_000_000_000_000_000_i128);
+        assert_eq!(
+            x.exp_down().map(|x| x.raw()),
+            Ok(2_718_281_828_459_045_235_360_287_471)
+        );
+        assert_eq!(
+            x.exp_up().map(|x| x.raw()),
+            Ok(2_718_281_828_459_045_235_360_287_472)
+        );
+        let x = FixedPoint::<i128, 24>::new(i128::MIN);
+        assert_eq!(x.exp_down(), Ok(FixedPoint::new(0_i128)));
+        assert_eq!(x.exp_up(), Ok(FixedPoint::new(1_i128)));
+    }
+
+    #[test]
+    fn fuzz_exp_ln_bounds() {
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            // exp
+            let x = rng.gen_range(fixed_i256!(-42e18)..=fixed!(135e18));
+            let x = FixedPoint::<I256>::new(x.raw().asr(rng.gen_range(0..64)));
+            let expected = exp_reference(&from_wad(x.raw()));
+            let down = x.exp_down().unwrap();
+            let up = x.exp_up().unwrap();
+            assert!(from_wad(down.raw()) <= expected, "exp_down({x})");
+            assert!(from_wad(up.raw()) >= expected, "exp_up({x})");
+
+            // More than 18 decimals are within a few units in the last place.
+            let to_60 = |x: FixedPoint<I256, 27>| to_bigint(x.raw()) * BigInt::from(10).pow(33);
+            let x = rng.gen_range(
+                FixedPoint::<I256, 27>::new(int256!(-60e27))..=FixedPoint::new(int256!(60e27)),
+            );
+            let x = FixedPoint::<I256, 27>::new(x.raw().asr(rng.gen_range(0..64)));
+            let expected = exp_reference(&to_60(x));
+            let down = x.exp_down().unwrap();
+            let up = x.exp_up().unwrap();
+            assert!(to_60(down) <= expected, "exp_down({x})");
+            assert!(to_60(up) >= expected, "exp_up({x})");
+            assert!(up.raw() - down.raw() <= up.raw() / int256!(1e32) + 2);
+            let x = x.abs() + FixedPoint::new(1);
+            let expected = ln_reference(&to_60(x));
+            let down = x.ln_down().unwrap();
+            let up = x.ln_up().unwrap();
+            assert!(to_60(down) <= expected, "ln_down({x})");
+            assert!(to_60(up) >= expected, "ln_up({x})");
+            assert!(up.raw() - down.raw() <= int256!(2));
+
+            // ln
+            let x = rng.gen_range(fixed_i256!(1)..=FixedPoint::MAX);
+            let x = FixedPoint::<I256>::new(x.raw() >> rng.gen_range(0..255_usize));
+            if x.is_zero() {
+                continue;
+            }
+            let expected = ln_reference(&from_wad(x.raw()));
+            let down = x.ln_down().unwrap();
+            let up = x.ln_up().unwrap();
+            assert!(from_wad(down.raw()) <= expected, "ln_down({x})");
+            assert!(from_wad(up.raw()) >= expected, "ln_up({x})");
+        }
+    }
+
+    #[test]
+    fn fuzz_pow_bounds() {
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            let x = rng.gen_range(fixed_i256!(0.001e18)..=fixed!(1000e18));
+            let y = rng.gen_range(fixed_i256!(-10e18)..=fixed!(10e18));
+            let (Ok(down), Ok(up)) = (x.pow_down(y), x.pow_up(y)) else {
+                continue;
+            };
+            let ln_x = ln_reference(&from_wad(x.raw()));
+            let expected = exp_reference(&(ln_x * from_wad(y.raw()) / scale()));
+            assert!(from_wad(down.raw()) <= expected, "pow_down({x}, {y})");
+            assert!(from_wad(up.raw()) >= expected, "pow_up({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn test_precise() {
+        assert_eq!(
+            fixed_u256!(2e18).ln_precise(),
+            Ok(fixed!(0.693147180559945309e18))
+        );
+        assert_eq!(
+            fixed_i256!(0.5e18).ln_precise(),
+            Ok(fixed!(-0.693147180559945309e18))
+        );
+        assert_eq!(
+            FixedPoint::<U256>::MAX.ln_precise(),
+            Ok(fixed!(135.999146549453176898e18))
+        );
+        assert_eq!(
+            fixed_u256!(2e18).pow_precise(fixed!(0.5e18)),
+            Ok(fixed!(1.414213562373095049e18))
+        );
+        assert_eq!(
+            fixed_i256!(2e18).pow_precise(fixed!(-0.5e18)),
+            Ok(fixed!(0.707106781186547524e18))
+        );
+        assert_eq!(
+            FixedPoint::<U256, 6>::new(2_000_000).pow_precise(FixedPoint::new(10_000_000)),
+            Ok(FixedPoint::new(1_024_000_000))
+        );
+
+        // `ln(1 + 1e-9)` is `0.0000000009999999995`, which `ln` truncates.
+        let x = fixed_u256!(1.000000001e18);
+        assert_eq!(x.ln_precise(), Ok(fixed!(0.000000001e18)));
+        assert_eq!(ln(x.to_i256().unwrap()), Ok(int256!(999999999)));
+
+        // Compounding a per-second rate over a year is exact to the last
+        // place, while `pow` is off by about `1.6e-11`.
+        let seconds_per_year = fixed!(31536000e18);
+        assert_eq!(
+            x.pow_precise(seconds_per_year),
+            Ok(fixed!(1.032038528297639107e18))
+        );
+        assert_eq!(x.pow(seconds_per_year), Ok(fixed!(1.032038528281365923e18)));
+
+        // Invalid inputs, overflow and underflow.
+        assert_eq!(
+            fixed_u256!(0).ln_precise(),
+            Err(FixedPointError::LnInvalidInput)
+        );
+        assert_eq!(
+            fixed_i256!(0).pow_precise(fixed!(-1e18)),
+            Err(FixedPointError::DivisionByZero)
+        );
+        assert_eq!(fixed_u256!(0).pow_precise(fixed!(2e18)), Ok(fixed!(0)));
+        assert_eq!(fixed_u256!(5e18).pow_precise(fixed!(0)), Ok(fixed!(1e18)));
+        assert!(matches!(
+            fixed_u256!(10e18).pow_precise(fixed!(1000e18)),
+            Err(FixedPointError::PowOverflow { .. })
+        ));
+        assert_eq!(
+            fixed_u256!(0.5e18).pow_precise(fixed!(1000e18)),
+            Ok(fixed!(0))
+        );
+    }
+
+    #[test]
+    fn fuzz_precise() {
+        let wei = BigUint::from(10_u8).pow(42);
+        let mut rng = thread_rng();
+        let mut max_pow_error = BigUint::ZERO;
+        for _ in 0..1_000 {
+            // `ln_precise` is within one unit in the last place.
+            let x = rng.gen_range(fixed_i256!(1)..=FixedPoint::MAX);
+            let x = FixedPoint::<I256>::new(x.raw() >> rng.gen_range(0..255_usize));
+            if x.is_zero() {
+                continue;
+            }
+            let expected = ln_reference(&from_wad(x.raw()));
+            let actual = from_wad(x.ln_precise().unwrap().raw());
+            assert!((actual - expected).magnitude() <= &wei, "ln_precise({x})");
+
+            // `pow_precise` is within one unit in the last place for bases
+            // close to one and large exponents, where the error of `pow` is
+            // much larger.
+            let x = rng.gen_range(fixed_i256!(0.99e18)..=fixed!(1.01e18));
+            let y = rng.gen_range(fixed_i256!(-1000e18)..=fixed!(1000e18));
+            let ln_x = ln_reference(&from_wad(x.raw()));
+            let expected = exp_reference(&(ln_x * from_wad(y.raw()) / scale()));
+            let actual = from_wad(x.pow_precise(y).unwrap().raw());
+            assert!(
+                (actual - &expected).magnitude() <= &wei,
+                "pow_precise({x}, {y})"
+            );
+            let error = from_wad(x.pow(y).unwrap().raw()) - expected;
+            max_pow_error = max_pow_error.max(error.magnitude().clone());
+        }
+        assert!(max_pow_error > wei * 100_u8);
+    }
+}
@@ -1,9 +1,8 @@
 use std::ops::Shr;
 
 use ethers::types::{I256, U256};
-use eyre::{bail, Result};
 
-use crate::{int256, uint256};
+use crate::{int256, uint256, FixedPointError};
 
 /// Parses a string into a U256 with support for scientific and decimal
 /// notation.
@@ -17,7 +16,7 @@ use crate::{int256, uint256};
 /// let u = u256_from_str("1.1e18").unwrap();
 /// assert_eq!(u, U256::from(11) * U256::from(10).pow(U256::from(17)));
 /// ```
-pub fn u256_from_str(s: &str) -> Result<U256> {
+pub fn u256_from_str(s: &str) -> Result<U256, FixedPointError> {
     // Parse a string into a mantissa and an exponent. The U256 arithmetic will
     // overflow if the mantissa or the exponent are too large.
     let mut found_dot = false;
@@ -42,7 +41,10 @@ pub fn u256_from_str(s: &str) -> Result<U256> {
         } else if digit == '.' && !found_dot {
             found_dot = true;
         } else if digit != '_' {
-            bail!("Unexpected character in U256: {digit}");
+            return Err(FixedPointError::InvalidCharacter {
+                input: s.to_string(),
+                character: digit,
+            });
         }
     }
 
@@ -51,7 +53,9 @@ pub fn u256_from_str(s: &str) -> Result<U256> {
     // final result is an integer.
     let decimals = ethers::types::U256::from(decimals);
     if exponent < decimals {
-        bail!("Exponent {exponent} is too small for U256: {s}");
+        return Err(FixedPointError::ExponentTooSmall {
+            input: s.to_string(),
+        });
     }
 
     Ok(mantissa * ethers::types::U256::from(10).pow(exponent - decimals))
@@ -69,7 +73,7 @@ pub fn u256_from_str(s: &str) -> Result<U256> {
 /// let i = i256_from_str("-1.1e18").unwrap();
 /// assert_eq!(i, -I256::from(11) * I256::from(10).pow(17));
 /// ```
-pub fn i256_from_str(s: &str) -> Result<I256> {
+pub fn i256_from_str(s: &str) -> Result<I256, FixedPointError> {
     // Parse a string into a mantissa and an exponent. The U256 arithmetic will
     // overflow if the mantissa or the exponent are too large.
     let mut sign = ethers::types::I256::one();
@@ -97,7 +101,10 @@ pub fn i256_from_str(s: &str) -> Result<I256> {
         } else if digit == '.' && !found_dot {
             found_dot = true;
         } else if digit != '_' {
-            bail!("Unexpected character in I256: {digit}");
+            return Err(FixedPointError::InvalidCharacter {
+                input: s.to_string(),
+                character: digit,
+            });
         }
     }
 
@@ -105,7 +112,9 @@ pub fn i256_from_str(s: &str) -> Result<I256> {
     // exponent is too large. We also need to make sure that the final result is
     // an integer.
     if exponent < decimals {
-        bail!("Exponent {exponent} is too small for I256: {s}");
+        return Err(FixedPointError::ExponentTooSmall {
+            input: s.to_string(),
+        });
     }
 
     Ok(sign * mantissa * ethers::types::I256::from(10).pow(exponent - decimals))
@@ -113,7 +122,7 @@ pub fn i256_from_str(s: &str) -> Result<I256> {
 
 /// Math
 
-pub fn exp(mut x: I256) -> Result<I256> {
+pub fn exp(mut x: I256) -> Result<I256, FixedPointError> {
     // When the result is < 0.5 we return zero. This happens when x <=
     // floor(log(0.5e18) * 1e18) ~ -42e18
     if x <= I256::from(-42139678854452767551_i128) {
@@ -123,7 +132,7 @@ pub fn exp(mut x: I256) -> Result<I256> {
     // When the result is > (2**255 - 1) / 1e18 we can not represent it as an
     // int. This happens when x >= floor(log((2**255 - 1) / 1e18) * 1e18) ~ 135.
     if x >= int256!(135305999368893231589) {
-        bail!("invalid exponent {x}");
+        return Err(FixedPointError::ExpInvalidExponent);
     }
 
     // x is now in the range (-42, 136) * 1e18. Convert to (-42, 136) * 2**96
@@ -203,9 +212,9 @@ pub fn exp(mut x: I256) -> Result<I256> {
     Ok(r)
 }
 
-pub fn ln(mut x: I256) -> Result<I256> {
+pub fn ln(mut x: I256) -> Result<I256, FixedPointError> {
     if x <= I256::zero() {
-        bail!("Cannot calculate ln of of negative number or zero.");
+        return Err(FixedPointError::LnInvalidInput);
     }
 
     // We want to convert x from 10**18 fixed point to 2**96 fixed point. We do
@@ -296,6 +305,7 @@ pub fn ln(mut x: I256) -> Result<I256> {
 #[cfg(test)]
 mod tests {
     use ethers::signers::Signer;
+    use eyre::Result;
     use hyperdrive_wrappers::wrappers::mock_fixed_point_math::MockFixedPointMath;
     use rand::{thread_rng, Rng};
     use test_utils::{chain::Chain, constants::DEPLOYER};
@@ -303,6 +313,29 @@ mod tests {
     use super::*;
     use crate::{fixed, uint256, FixedPoint};
 
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            u256_from_str("1.5"),
+            Err(FixedPointError::ExponentTooSmall {
+                input: "1.5".to_string()
+            })
+        );
+        assert_eq!(
+            i256_from_str("-1x"),
+            Err(FixedPointError::InvalidCharacter {
+                input: "-1x".to_string(),
+                character: 'x'
+            })
+        );
+        assert_eq!(
+            exp(int256!(135305999368893231589)),
+            Err(FixedPointError::ExpInvalidExponent)
+        );
+        assert_eq!(ln(I256::zero()), Err(FixedPointError::LnInvalidInput));
+        assert_eq!(ln(int256!(-1e18)), Err(FixedPointError::LnInvalidInput));
+    }
+
     #[tokio::test]
     async fn fuzz_exp_narrow() -> Result<()> {
         let chain = Chain::connect(None, None).await?;
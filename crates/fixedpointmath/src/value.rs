@@ -4,46 +4,68 @@ use std::{
 };
 
 use ethers::types::U256;
-use eyre::{eyre, Result};
 use paste::paste;
 
+use crate::FixedPointError;
+
 /// Adds `from_<type>` and `to_<type>` conversion functions for a list of types.
 macro_rules! conversion_fns {
     ($($type_name:ident),*) => {
         $(
         paste! {
-            fn [<from_ $type_name:snake>](value: $type_name) -> Result<Self> {
-                Self::try_from(value).map_err(|_| {
-                    eyre!(
-                        r#"Failed to convert {type} to underlying FixedPointValue type:
-    {type} value: {value:?}
-    Underlying range: {min:?} to {max:?}
-"#,
-                        type = stringify!($type_name),
-                        min = Self::MIN,
-                        max = Self::MAX,
-                    )
-                })
+            fn [<from_ $type_name:snake>](value: $type_name) -> Result<Self, FixedPointError> {
+                Self::try_from(value).map_err(|_| FixedPointError::conversion::<Self>(value))
             }
 
-            fn [<to_ $type_name:snake>](self) -> Result<$type_name> {
-                self.try_into().map_err(|_| {
-                    eyre!(
-                        "Failed to convert underlying FixedPointValue to {type}: {self:?}",
-                        type = stringify!($type_name)
-                   )
-                })
+            fn [<to_ $type_name:snake>](self) -> Result<$type_name, FixedPointError> {
+                self.try_into().map_err(|_| FixedPointError::conversion::<$type_name>(self))
             }
         })*
     };
 }
 
+/// Computes the powers of ten from `10^0` to `10^(N - 1)` as `U256`s at
+/// compile time.
+///
+/// # Panics
+///
+/// If `10^(N - 1)` overflows `U256`, i.e., if `N` is greater than `78`.
+// Public only for the expansion of the exported `fixed_point_value_impl!`
+// macro.
+#[doc(hidden)]
+pub const fn powers_of_ten<const N: usize>() -> [U256; N] {
+    let mut powers = [U256([1, 0, 0, 0]); N];
+    let mut i = 1;
+    while i < N {
+        // Multiply the previous power by 10 one 64-bit limb at a time.
+        let U256(limbs) = powers[i - 1];
+        let mut next = [0_u64; 4];
+        let mut carry = 0_u128;
+        let mut j = 0;
+        while j < 4 {
+            let product = limbs[j] as u128 * 10 + carry;
+            next[j] = product as u64;
+            carry = product >> 64;
+            j += 1;
+        }
+        if carry != 0 {
+            panic!("Power of ten overflows U256.");
+        }
+        powers[i] = U256(next);
+        i += 1;
+    }
+    powers
+}
+
 /// A value that can be used to perform fixed-point math.
 ///
-/// All methods have default implementations based on comparisons to `0`, but
-/// can be overridden to provide more efficient alternatives.
+/// Most methods have default implementations based on comparisons to `0`, but
+/// can be overridden to provide more efficient alternatives. The checked and
+/// overflowing arithmetic methods are implemented by the
+/// `fixed_point_value_impl!` macro.
 pub trait FixedPointValue:
-    Copy
+    'static
+    + Copy
     + Debug
     + Default
     + Sized
@@ -74,9 +96,52 @@ pub trait FixedPointValue:
     /// Must be `0..=2^256 - 1`.
     const MAX: Self;
 
-    /// The maximum number of decimal places the value can support.
+    /// The maximum number of decimal places the value can support, i.e., the
+    /// largest `n` for which `10^n` can be represented by the type.
     const MAX_DECIMALS: u8 = 18;
 
+    /// The powers of ten that can be represented by the type, i.e.,
+    /// `10^0..=10^MAX_DECIMALS`. These are used to build the scale of each
+    /// `FixedPoint<Self, D>` at compile time.
+    const POWERS_OF_TEN: &'static [Self];
+
+    /// The mathematical constants of `FixedPoint`, e.g., `FixedPoint::E`,
+    /// correctly rounded to `0..=MAX_DECIMALS` decimal places. The values for
+    /// the constant at index `i` start at index `i * (MAX_DECIMALS + 1)`.
+    const CONSTANTS: &'static [Self];
+
+    /// Adds `other` to self, returning `None` if the result overflows.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Subtracts `other` from self, returning `None` if the result overflows.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Adds `other` to self, returning the wrapped result and whether the
+    /// addition overflowed.
+    fn overflowing_add(self, other: Self) -> (Self, bool);
+
+    /// Subtracts `other` from self, returning the wrapped result and whether
+    /// the subtraction overflowed.
+    fn overflowing_sub(self, other: Self) -> (Self, bool);
+
+    /// Multiplies self by `other`, returning the wrapped result and whether
+    /// the multiplication overflowed.
+    fn overflowing_mul(self, other: Self) -> (Self, bool);
+
+    /// Divides self by `other`, returning the wrapped result and whether the
+    /// division overflowed, i.e., if self is the minimum value of a signed
+    /// integer and `other` is `-1`.
+    ///
+    /// # Panics
+    ///
+    /// If `other` is zero.
+    fn overflowing_div(self, other: Self) -> (Self, bool) {
+        if Self::is_signed() && self == Self::MIN && other == Self::from(0) - Self::from(1) {
+            return (Self::MIN, true);
+        }
+        (self / other, false)
+    }
+
     /// Whether the value supports negation.
     fn is_signed() -> bool {
         Self::MIN.is_negative()
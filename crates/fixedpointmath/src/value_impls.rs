@@ -1,7 +1,6 @@
 use ethers::types::{I256, U256};
-use eyre::eyre;
 
-use crate::{FixedPoint, FixedPointValue};
+use crate::{FixedPoint, FixedPointError, FixedPointValue};
 
 /// Implements [`FixedPointValue`] and conversion traits for the given type.
 ///
@@ -12,15 +11,21 @@ use crate::{FixedPoint, FixedPointValue};
 ///     type = U256,
 ///     MAX = U256::MAX,
 ///     MIN = U256::zero(),
+///     FROM_U256 = std::convert::identity,
+///     MAX_DECIMALS = 77,
 ///     from = u128,
 ///     try_from = i128 | I256,
 /// );
 /// ```
 ///
 /// # Parameters
-/// - `type`: The type to implement `FixedPointValue` for.
+/// - `type`: The type to implement `FixedPointValue` for. The type must have
+///   inherent `checked_add`, `checked_sub`, `overflowing_add`,
+///   `overflowing_sub` and `overflowing_mul` methods.
 /// - `MAX`: The maximum value of the type. `-(2^256-1)..=(2^256-1)`.
 /// - `MIN`: The minimum value of the type. `0..=(2^256-1)`.
+/// - `FROM_U256`: A `const fn` that converts a `U256` to the type, used to
+///   build the type's powers of ten at compile time.
 /// - `MAX_DECIMALS`: *(Optional)* The maximum number of decimal places the value can support.
 /// - `from`: *(Optional)* Other types that can convert to the given type.
 /// - `try_from`: *(Optional)* Other types that can try to convert to the given type.
@@ -29,7 +34,8 @@ macro_rules! fixed_point_value_impl {
   (
       type = $t:ty,
       MAX = $max:expr,
-      MIN = $min:expr$(,
+      MIN = $min:expr,
+      FROM_U256 = $from_u256:path$(,
       MAX_DECIMALS = $decimals:expr)?$(,
       from = $($from:ty)|+)?$(,
       try_from = $($try_from:ty)|+)?$(,)?
@@ -40,34 +46,73 @@ macro_rules! fixed_point_value_impl {
         $(
             const MAX_DECIMALS: u8 = $decimals;
         )?
+        const POWERS_OF_TEN: &'static [Self] = &{
+            const N: usize = <$t as FixedPointValue>::MAX_DECIMALS as usize + 1;
+            let powers = $crate::powers_of_ten::<N>();
+            let mut table = [$from_u256(powers[0]); N];
+            let mut i = 1;
+            while i < N {
+                table[i] = $from_u256(powers[i]);
+                i += 1;
+            }
+            table
+        };
+        const CONSTANTS: &'static [Self] = &{
+            const N: usize = <$t as FixedPointValue>::MAX_DECIMALS as usize + 1;
+            const M: usize = $crate::CONSTANT_COUNT * N;
+            let constants = $crate::constant_table::<N, M>();
+            let mut table = [$from_u256(constants[0]); M];
+            let mut i = 1;
+            while i < M {
+                table[i] = $from_u256(constants[i]);
+                i += 1;
+            }
+            table
+        };
+
+        fn checked_add(self, other: Self) -> Option<Self> {
+            <$t>::checked_add(self, other)
+        }
+
+        fn checked_sub(self, other: Self) -> Option<Self> {
+            <$t>::checked_sub(self, other)
+        }
+
+        fn overflowing_add(self, other: Self) -> (Self, bool) {
+            <$t>::overflowing_add(self, other)
+        }
+
+        fn overflowing_sub(self, other: Self) -> (Self, bool) {
+            <$t>::overflowing_sub(self, other)
+        }
+
+        fn overflowing_mul(self, other: Self) -> (Self, bool) {
+            <$t>::overflowing_mul(self, other)
+        }
     }
 
 
-    impl From<FixedPoint<$t>> for $t {
-        fn from(f: FixedPoint<$t>) -> Self {
+    impl<const D: u8> From<FixedPoint<$t, D>> for $t {
+        fn from(f: FixedPoint<$t, D>) -> Self {
             f.raw()
         }
     }
 
     $(
       $(
-        impl From<$from> for FixedPoint<$t> {
+        impl<const D: u8> From<$from> for FixedPoint<$t, D> {
             fn from(f: $from) -> Self {
                 FixedPoint::new(f)
             }
         }
 
-        impl TryFrom<FixedPoint<$t>> for $from {
-            type Error = eyre::ErrReport;
-
-            fn try_from(value: FixedPoint<$t>) -> eyre::Result<Self> {
-                value.raw().try_into().map_err(|_| {
-                    eyre!(
-                        "Failed to convert underlying FixedPointValue of type {type} to {to_type}: {value:?}",
-                        type = stringify!($t),
-                        to_type = stringify!($from),
-                        value = value.raw()
-                    )
+        impl<const D: u8> TryFrom<FixedPoint<$t, D>> for $from {
+            type Error = FixedPointError;
+
+            fn try_from(value: FixedPoint<$t, D>) -> Result<Self, FixedPointError> {
+                value.raw().try_into().map_err(|_| FixedPointError::ConversionOutOfRange {
+                    value: format!("{:?}", value.raw()),
+                    to: stringify!($from),
                 })
             }
           }
@@ -75,25 +120,21 @@ macro_rules! fixed_point_value_impl {
     )*
     $(
       $(
-        impl TryFrom<$try_from> for FixedPoint<$t> {
-            type Error = eyre::ErrReport;
+        impl<const D: u8> TryFrom<$try_from> for FixedPoint<$t, D> {
+            type Error = FixedPointError;
 
-            fn try_from(value: $try_from) -> eyre::Result<Self> {
+            fn try_from(value: $try_from) -> Result<Self, FixedPointError> {
                 FixedPoint::try_from(value)
             }
         }
 
-        impl TryFrom<FixedPoint<$t>> for $try_from {
-            type Error = eyre::ErrReport;
-
-            fn try_from(value: FixedPoint<$t>) -> eyre::Result<Self> {
-                value.raw().try_into().map_err(|_| {
-                    eyre!(
-                        "Failed to convert underlying FixedPointValue of type {type} to {to_type}: {value:?}",
-                        type = stringify!($t),
-                        to_type = stringify!($try_from),
-                        value = value.raw()
-                    )
+        impl<const D: u8> TryFrom<FixedPoint<$t, D>> for $try_from {
+            type Error = FixedPointError;
+
+            fn try_from(value: FixedPoint<$t, D>) -> Result<Self, FixedPointError> {
+                value.raw().try_into().map_err(|_| FixedPointError::ConversionOutOfRange {
+                    value: format!("{:?}", value.raw()),
+                    to: stringify!($try_from),
                 })
             }
           }
@@ -102,10 +143,20 @@ macro_rules! fixed_point_value_impl {
   }
 }
 
+const fn i128_from_u256(value: U256) -> i128 {
+    value.low_u128() as i128
+}
+
+const fn u128_from_u256(value: U256) -> u128 {
+    value.low_u128()
+}
+
 fixed_point_value_impl!(
     type = i128,
     MAX = i128::MAX,
     MIN = i128::MIN,
+    FROM_U256 = i128_from_u256,
+    MAX_DECIMALS = 38,
     try_from = u128 | I256 | U256,
 );
 
@@ -113,6 +164,8 @@ fixed_point_value_impl!(
     type = u128,
     MAX = u128::MAX,
     MIN = u128::MIN,
+    FROM_U256 = u128_from_u256,
+    MAX_DECIMALS = 38,
     try_from = i128 | I256 | U256,
 );
 
@@ -120,6 +173,8 @@ fixed_point_value_impl!(
     type = I256,
     MAX = I256::MAX,
     MIN = I256::MIN,
+    FROM_U256 = I256::from_raw,
+    MAX_DECIMALS = 76,
     from = i128 | u128,
     try_from = U256,
 );
@@ -128,6 +183,8 @@ fixed_point_value_impl!(
     type = U256,
     MAX = U256::MAX,
     MIN = U256::zero(),
+    FROM_U256 = std::convert::identity,
+    MAX_DECIMALS = 77,
     from = u128,
     try_from = i128 | I256,
 );
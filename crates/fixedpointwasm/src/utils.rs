@@ -124,14 +124,15 @@ pub fn random_fixed(params: Option<IGenerateRandomParams>) -> Result<WasmFixedPo
 /// Get the natural logarithm of a fixed-point number.
 ///
 /// @param x - The value to calculate the natural logarithm of.
-/// scaled raw value.
+///
+/// @param decimals - The number of decimal places to use. Max is `18`.
+/// Defaults to `18`.
 #[wasm_bindgen(skip_jsdoc)]
-pub fn ln(x: Numberish) -> Result<WasmFixedPoint, Error> {
-    let fixed: FixedPoint<I256> = x.try_into()?;
-    let int_result = fixedpointmath::ln(fixed.raw()).to_result()?;
+pub fn ln(x: Numberish, decimals: Option<u8>) -> Result<WasmFixedPoint, Error> {
+    let fixed = WasmFixedPoint::new(x, decimals)?;
     let result = WasmFixedPoint {
-        inner: int_result.fixed(),
-        decimals: fixed.decimals(),
+        inner: fixed.inner.ln().to_result()?,
+        decimals: fixed.decimals,
     };
     Ok(result)
 }